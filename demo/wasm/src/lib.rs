@@ -51,13 +51,14 @@ impl TessellatorJs {
     }
 
     /// Tessellate with full control over element type and polygon size.
-    /// element_type: 0=Polygons 1=ConnectedPolygons 2=BoundaryContours
+    /// element_type: 0=Polygons 1=ConnectedPolygons 2=BoundaryContours 3=EdgeList
     pub fn tessellate_full(&mut self, winding: u32, element_type: u32, poly_size: u32) -> bool {
         let wr = winding_rule(winding);
         let et = match element_type {
             0 => ElementType::Polygons,
             1 => ElementType::ConnectedPolygons,
             2 => ElementType::BoundaryContours,
+            3 => ElementType::EdgeList,
             _ => ElementType::Polygons,
         };
         self.inner.tessellate(wr, et, poly_size as usize, 2, None)
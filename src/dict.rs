@@ -52,6 +52,16 @@ impl Dict {
         Dict { nodes: vec![head] }
     }
 
+    /// Remove every key, keeping the node arena's capacity.
+    pub fn clear(&mut self) {
+        self.nodes.truncate(1);
+        self.nodes[DICT_HEAD as usize] = DictNode {
+            key: INVALID,
+            next: DICT_HEAD,
+            prev: DICT_HEAD,
+        };
+    }
+
     /// dictInsert: insert a key at the back (before the head sentinel).
     pub fn insert<F>(&mut self, key: u32, leq: &F) -> NodeIdx
     where
@@ -0,0 +1,216 @@
+// Copyright 2025 Lars Brubaker
+// License: SGI Free Software License B (MIT-compatible)
+//
+// Anti-aliasing fringe (halo strip) built from `edge_flags()`.
+//
+// Every boundary edge of the fill (flag `1`) is extruded outwards by a
+// fixed width into a quad whose inner side lies on the edge with coverage 1
+// and whose outer side has coverage 0; drawing the fill plus the fringe with
+// interpolated coverage as alpha gives analytically anti-aliased edges.
+// Interior edges (flag `0`) get nothing.
+//
+// The boundary edges are chained into loops at their shared vertices, so
+// neighbouring quads meet at one outer point instead of overlapping or
+// leaving a notch.  At convex corners that point is the miter; when the
+// miter would reach further than `MITER_LIMIT` widths, the corner is
+// beveled with one extra triangle.  At concave corners the quads meet at the
+// miter point, pulled in to `MITER_LIMIT` widths for very sharp corners.
+// Where the fill touches itself at a single vertex, each boundary edge
+// coming in is chained to the one leaving next around the outside.
+//
+// Only x and y of each vertex are used, and fringe triangles wind the same
+// way as the fill's.
+
+use std::collections::HashMap;
+
+use crate::geom::Real;
+use crate::tess::{ElementType, TESS_UNDEF};
+
+/// Longest miter, in fringe widths, before a convex corner is beveled.
+pub const MITER_LIMIT: Real = 4.0;
+
+/// A boundary edge of the fill, with its unit outward normal.
+struct BoundaryEdge {
+    from: u32,
+    to: u32,
+    normal: [Real; 2],
+}
+
+/// Fringe vertices and triangles for the boundary of a tessellation.
+#[derive(Clone, Debug, Default)]
+pub struct Fringe {
+    /// x and y of each fringe vertex.
+    vertices: Vec<Real>,
+    /// 1 on the boundary, 0 at the outer rim.
+    coverage: Vec<Real>,
+    /// Tessellation vertex each fringe vertex was extruded from.
+    sources: Vec<u32>,
+    triangles: Vec<u32>,
+}
+
+impl Fringe {
+    /// Build from the output of a `tessellate` call with `Polygons` or
+    /// `ConnectedPolygons`: `vertices()` with `vertex_size` components
+    /// each, `elements()` and `edge_flags()` as written for `element_type`
+    /// and `poly_size`.  Other element types give an empty fringe.
+    pub fn new(
+        vertices: &[Real],
+        vertex_size: usize,
+        elements: &[u32],
+        edge_flags: &[u8],
+        element_type: ElementType,
+        poly_size: usize,
+        width: Real,
+    ) -> Self {
+        let stride = match element_type {
+            ElementType::Polygons => poly_size,
+            ElementType::ConnectedPolygons => poly_size * 2,
+            _ => return Fringe::default(),
+        };
+        let xy = |v: u32| [vertices[v as usize * vertex_size], vertices[v as usize * vertex_size + 1]];
+
+        // The fill's winding decides which side of an edge is outside.
+        let polys: Vec<&[u32]> = elements
+            .chunks_exact(stride)
+            .map(|e| {
+                let count = e[..poly_size].iter().take_while(|&&v| v != TESS_UNDEF).count();
+                &e[..count]
+            })
+            .collect();
+        let mut area = 0.0;
+        for poly in &polys {
+            for (i, &v) in poly.iter().enumerate() {
+                let (a, b) = (xy(v), xy(poly[(i + 1) % poly.len()]));
+                area += a[0] * b[1] - b[0] * a[1];
+            }
+        }
+        let winding = if area < 0.0 { -1.0 } else { 1.0 };
+
+        let mut edges = Vec::new();
+        for (poly, flags) in polys.iter().zip(edge_flags.chunks_exact(poly_size)) {
+            for (i, &from) in poly.iter().enumerate() {
+                let to = poly[(i + 1) % poly.len()];
+                let (a, b) = (xy(from), xy(to));
+                let len = (b[0] - a[0]).hypot(b[1] - a[1]);
+                if flags[i] == 0 || len == 0.0 {
+                    continue;
+                }
+                let normal = [winding * (b[1] - a[1]) / len, winding * (a[0] - b[0]) / len];
+                edges.push(BoundaryEdge { from, to, normal });
+            }
+        }
+
+        // Chain each edge to the boundary edge leaving its end vertex next
+        // around the outside: the first one met turning from the reversed
+        // edge away from the fill.
+        let mut leaving: HashMap<u32, Vec<usize>> = HashMap::new();
+        for (i, e) in edges.iter().enumerate() {
+            leaving.entry(e.from).or_default().push(i);
+        }
+        let direction = |e: &BoundaryEdge| {
+            let (a, b) = (xy(e.from), xy(e.to));
+            (b[1] - a[1]).atan2(b[0] - a[0])
+        };
+        let pi = std::f64::consts::PI as Real;
+        let next: Vec<Option<usize>> = edges
+            .iter()
+            .map(|e| {
+                let back = direction(e) + pi;
+                leaving.get(&e.to)?.iter().copied().min_by(|&x, &y| {
+                    let turn = |o: usize| {
+                        let t = (winding * (direction(&edges[o]) - back)).rem_euclid(2.0 * pi);
+                        if t == 0.0 {
+                            2.0 * pi
+                        } else {
+                            t
+                        }
+                    };
+                    turn(x).total_cmp(&turn(y))
+                })
+            })
+            .collect();
+
+        // One join per chained pair at the shared vertex: an inner point
+        // and one outer point (miter) or two (bevel).  `start[e]` / `end[e]`
+        // are the (inner, outer) fringe vertices at each end of edge `e`.
+        let mut fringe = Fringe::default();
+        let mut start = vec![(u32::MAX, u32::MAX); edges.len()];
+        let mut end = vec![(u32::MAX, u32::MAX); edges.len()];
+        for (e_in, e_out) in next.iter().enumerate().filter_map(|(i, n)| n.map(|n| (i, n))) {
+            let v = edges[e_in].to;
+            let p = xy(v);
+            let (n_in, n_out) = (edges[e_in].normal, edges[e_out].normal);
+            let inner = fringe.push(p, 1.0, v);
+            let bisector = [n_in[0] + n_out[0], n_in[1] + n_out[1]];
+            let cos = n_in[0] * n_out[0] + n_in[1] * n_out[1];
+            let convex = winding * (n_in[0] * n_out[1] - n_in[1] * n_out[0]) >= 0.0;
+            // The miter is `bisector / (1 + cos)` widths out, `1 / cos(θ/2)`
+            // widths long.
+            let miter_len = if 1.0 + cos > 0.0 { (2.0 / (1.0 + cos)).sqrt() } else { Real::INFINITY };
+            if convex && miter_len > MITER_LIMIT {
+                let a = fringe.push([p[0] + n_in[0] * width, p[1] + n_in[1] * width], 0.0, v);
+                let b = fringe.push([p[0] + n_out[0] * width, p[1] + n_out[1] * width], 0.0, v);
+                fringe.triangles.extend([inner, a, b]);
+                end[e_in] = (inner, a);
+                start[e_out] = (inner, b);
+            } else {
+                let scale = width / (1.0 + cos) * (MITER_LIMIT / miter_len).min(1.0);
+                let m = fringe.push([p[0] + bisector[0] * scale, p[1] + bisector[1] * scale], 0.0, v);
+                end[e_in] = (inner, m);
+                start[e_out] = (inner, m);
+            }
+        }
+
+        // Edges left unchained (open boundary chains in malformed input) get
+        // square ends.
+        for (i, e) in edges.iter().enumerate() {
+            let mut square = |v: u32| {
+                let p = xy(v);
+                let inner = fringe.push(p, 1.0, v);
+                (inner, fringe.push([p[0] + e.normal[0] * width, p[1] + e.normal[1] * width], 0.0, v))
+            };
+            if start[i].0 == u32::MAX {
+                start[i] = square(e.from);
+            }
+            if end[i].0 == u32::MAX {
+                end[i] = square(e.to);
+            }
+            let ((a_in, a_out), (b_in, b_out)) = (start[i], end[i]);
+            fringe.triangles.extend([a_in, a_out, b_out, a_in, b_out, b_in]);
+        }
+        fringe
+    }
+
+    fn push(&mut self, p: [Real; 2], coverage: Real, source: u32) -> u32 {
+        self.vertices.extend(p);
+        self.coverage.push(coverage);
+        self.sources.push(source);
+        self.coverage.len() as u32 - 1
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.coverage.len()
+    }
+
+    /// x and y of each fringe vertex.
+    pub fn vertices(&self) -> &[Real] {
+        &self.vertices
+    }
+
+    /// Coverage of each fringe vertex: 1 on the fill's boundary, 0 on the
+    /// outer rim.
+    pub fn coverage(&self) -> &[Real] {
+        &self.coverage
+    }
+
+    /// The tessellation vertex each fringe vertex belongs to, for copying
+    /// per-vertex attributes such as colour.
+    pub fn sources(&self) -> &[u32] {
+        &self.sources
+    }
+
+    /// Fringe triangles as triples of fringe vertex indices.
+    pub fn triangles(&self) -> &[u32] {
+        &self.triangles
+    }
+}
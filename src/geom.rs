@@ -7,6 +7,8 @@
 // These are exact translations of the C functions with identical floating-point
 // behavior to ensure mathematical equivalence with the original library.
 
+use crate::predicates::orient2d;
+
 /// Internal coordinate type used throughout the sweep and all geometric
 /// predicates.
 ///
@@ -87,6 +89,27 @@ pub fn edge_sign(u_s: Real, u_t: Real, v_s: Real, v_t: Real, w_s: Real, w_t: Rea
     edge_eval(u_s, u_t, v_s, v_t, w_s, w_t)
 }
 
+/// `edge_sign` for `TessOption::ExactPredicates`: the same sign, decided
+/// exactly by `predicates::orient2d` (`edge_eval` scaled by the positive
+/// `w.s - u.s`) instead of rounded through `edge_eval`'s division.
+pub fn edge_sign_exact(u_s: Real, u_t: Real, v_s: Real, v_t: Real, w_s: Real, w_t: Real) -> Real {
+    if u_s < w_s {
+        orient2d([u_s, u_t], [w_s, w_t], [v_s, v_t])
+    } else {
+        0.0
+    }
+}
+
+/// The sweep's sign test: `edge_sign_exact` when `exact`, otherwise the
+/// libtess2-compatible `edge_sign`.
+pub fn edge_sign_fn(exact: bool) -> fn(Real, Real, Real, Real, Real, Real) -> Real {
+    if exact {
+        edge_sign_exact
+    } else {
+        edge_sign
+    }
+}
+
 /// Like edge_eval but with s and t transposed.
 pub fn trans_eval(u_s: Real, u_t: Real, v_s: Real, v_t: Real, w_s: Real, w_t: Real) -> Real {
     // debug_assert!(trans_leq(u_s, u_t, v_s, v_t) && trans_leq(v_s, v_t, w_s, w_t));
@@ -121,6 +144,11 @@ pub fn vert_ccw(u_s: Real, u_t: Real, v_s: Real, v_t: Real, w_s: Real, w_t: Real
     u_s * (v_t - w_t) + v_s * (w_t - u_t) + w_s * (u_t - v_t) >= 0.0
 }
 
+/// `vert_ccw` decided exactly (`TessOption::ExactPredicates`).
+pub fn vert_ccw_exact(u_s: Real, u_t: Real, v_s: Real, v_t: Real, w_s: Real, w_t: Real) -> bool {
+    orient2d([u_s, u_t], [v_s, v_t], [w_s, w_t]) >= 0.0
+}
+
 /// L1 distance between two vertices.
 #[inline]
 pub fn vert_l1_dist(u_s: Real, u_t: Real, v_s: Real, v_t: Real) -> Real {
@@ -4,10 +4,17 @@
 
 pub mod bucketalloc;
 pub mod dict;
+pub mod fringe;
 pub mod geom;
 pub mod mesh;
+pub mod navmesh;
+pub mod predicates;
 pub mod priorityq;
+pub mod section;
 pub mod sweep;
 pub mod tess;
 
-pub use tess::{ElementType, TessOption, TessStatus, Tessellator, TessellatorApi, WindingRule};
+pub use tess::{
+    BrepFace, ContourHandle, ContourNode, ElementType, FaceError, FaceMesh, Orientation, PlaneFit, PolygonWithHoles,
+    TessOption, TessStatus, Tessellator, TessellatorApi, UvMapping, VoronoiCell, WindingRule,
+};
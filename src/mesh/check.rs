@@ -0,0 +1,326 @@
+// Copyright 2025 Lars Brubaker
+// Mesh invariant checker (libtess2's tessMeshCheckMesh).
+//
+// Instead of asserting, every broken invariant is collected into a
+// `MeshReport`, so a caller can print the whole picture of a corrupted mesh.
+// Walks are bounded by the arena sizes, and an element whose links are
+// unusable is reported once and then skipped, so the check itself never
+// panics or loops on a broken mesh.
+
+use std::fmt;
+
+use super::{EdgeIdx, Mesh, E_HEAD, F_HEAD, INVALID, V_HEAD};
+
+/// The kind of mesh element a defect is about.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MeshElement {
+    Vertex,
+    Face,
+    Edge,
+}
+
+/// One broken invariant found by [`Mesh::check`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MeshDefect {
+    /// A link field is `INVALID` or outside its arena (`lface` may be
+    /// `INVALID`: edges on the border of a mesh with its exterior
+    /// discarded have no left face).
+    BadIndex { element: MeshElement, index: u32, field: &'static str },
+    /// A link points at a vertex, face or edge that has been deleted.
+    DeadReference { element: MeshElement, index: u32, field: &'static str },
+    /// `next.prev` does not lead back in the vertex or face list, or
+    /// `e.sym.next` is not `prev.sym` in the edge list.
+    BrokenList { element: MeshElement, index: u32 },
+    /// The vertex, face or edge list does not return to its sentinel.
+    OpenList { element: MeshElement },
+    /// The `onext` ring of a vertex or the `lnext` ring of a face does not
+    /// close within the edge count.
+    OpenRing { element: MeshElement, index: u32 },
+    /// `lnext` and `onext` disagree: `e.lnext.onext.sym != e` or
+    /// `e.onext.sym.lnext != e`.
+    SpliceMismatch { edge: EdgeIdx },
+    /// An edge in the `onext` ring of a vertex has another origin.
+    WrongOrigin { edge: EdgeIdx, expected: u32, found: u32 },
+    /// An edge in the `lnext` ring of a face has another left face.
+    WrongFace { edge: EdgeIdx, expected: u32, found: u32 },
+    /// A live edge is not reached by the ring walk of its origin or left
+    /// face.
+    Unreached { edge: EdgeIdx },
+    /// `constrained` differs between the two halves of an edge pair.
+    AsymmetricPair { edge: EdgeIdx },
+}
+
+/// Result of [`Mesh::check`]: element counts and every defect found.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MeshReport {
+    pub vertices: usize,
+    pub faces: usize,
+    /// Live edge pairs.
+    pub edges: usize,
+    pub defects: Vec<MeshDefect>,
+}
+
+impl MeshReport {
+    pub fn is_ok(&self) -> bool {
+        self.defects.is_empty()
+    }
+}
+
+impl fmt::Display for MeshReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} vertices, {} faces, {} edges, {} defects",
+            self.vertices,
+            self.faces,
+            self.edges,
+            self.defects.len()
+        )?;
+        for d in &self.defects {
+            write!(f, "\n  {:?}", d)?;
+        }
+        Ok(())
+    }
+}
+
+impl Mesh {
+    /// Check every half-edge invariant: `sym` pairs, the `onext` / `lnext`
+    /// cycles and their agreement, the vertex, face and edge lists, and
+    /// that every vertex and face `an_edge` starts a ring of edges with
+    /// that origin / left face.  Returns every defect instead of stopping
+    /// at the first one.
+    pub fn check(&self) -> MeshReport {
+        let mut report = MeshReport::default();
+        let defects = &mut report.defects;
+        let (nv, nf, ne) = (self.verts.len() as u32, self.faces.len() as u32, self.edges.len() as u32);
+        let live_vert = |v: u32| v < nv && self.verts[v as usize].next != INVALID;
+        let live_face = |f: u32| f < nf && self.faces[f as usize].next != INVALID;
+        let live_edge = |e: u32| e < ne && self.edges[(e & !1) as usize].next != INVALID;
+
+        // Live edges, and the links every ring walk relies on.
+        let mut usable = vec![false; ne as usize];
+        let mut prev = E_HEAD;
+        let mut e = self.edges[E_HEAD as usize].next;
+        let mut steps = 0;
+        while e != E_HEAD {
+            if e >= ne || steps > ne {
+                defects.push(MeshDefect::OpenList { element: MeshElement::Edge });
+                break;
+            }
+            let next = self.edges[e as usize].next;
+            if self.edges[(e ^ 1) as usize].next != prev ^ 1 {
+                defects.push(MeshDefect::BrokenList { element: MeshElement::Edge, index: e });
+            }
+            report.edges += 1;
+            for half in [e, e ^ 1] {
+                let he = &self.edges[half as usize];
+                let mut ok = true;
+                for (field, link, live) in [
+                    ("onext", he.onext, live_edge(he.onext)),
+                    ("lnext", he.lnext, live_edge(he.lnext)),
+                    ("org", he.org, live_vert(he.org)),
+                    // No left face is fine: `discard_exterior` leaves those.
+                    ("lface", he.lface, he.lface == INVALID || live_face(he.lface)),
+                ] {
+                    if !live {
+                        let bound = match field {
+                            "org" => nv,
+                            "lface" => nf,
+                            _ => ne,
+                        };
+                        let element = MeshElement::Edge;
+                        defects.push(if link == INVALID || link >= bound {
+                            MeshDefect::BadIndex { element, index: half, field }
+                        } else {
+                            MeshDefect::DeadReference { element, index: half, field }
+                        });
+                        ok = false;
+                    }
+                }
+                usable[half as usize] = ok;
+            }
+            if usable[e as usize] && usable[(e ^ 1) as usize] {
+                let (a, b) = (&self.edges[e as usize], &self.edges[(e ^ 1) as usize]);
+                if a.constrained != b.constrained {
+                    defects.push(MeshDefect::AsymmetricPair { edge: e });
+                }
+            }
+            prev = e;
+            e = next;
+            steps += 1;
+        }
+
+        for h in 0..ne {
+            if !usable[h as usize] {
+                continue;
+            }
+            let he = &self.edges[h as usize];
+            let (ln, on) = (he.lnext, he.onext);
+            if (usable[ln as usize] && self.edges[ln as usize].onext ^ 1 != h)
+                || (usable[(on ^ 1) as usize] && self.edges[(on ^ 1) as usize].lnext != h)
+            {
+                defects.push(MeshDefect::SpliceMismatch { edge: h });
+            }
+        }
+
+        // Vertex rings, then face rings: each must close, and together they
+        // must visit every usable half-edge exactly once.
+        let mut by_org = vec![false; ne as usize];
+        let mut by_face = vec![false; ne as usize];
+        let mut v = self.verts[V_HEAD as usize].next;
+        steps = 0;
+        while v != V_HEAD {
+            if v >= nv || steps > nv {
+                defects.push(MeshDefect::OpenList { element: MeshElement::Vertex });
+                break;
+            }
+            let vert = &self.verts[v as usize];
+            if vert.next >= nv || self.verts[vert.next as usize].prev != v {
+                defects.push(MeshDefect::BrokenList { element: MeshElement::Vertex, index: v });
+            }
+            report.vertices += 1;
+            self.check_ring(MeshElement::Vertex, v, vert.an_edge, &usable, &mut by_org, defects);
+            v = vert.next;
+            steps += 1;
+        }
+        let mut f = self.faces[F_HEAD as usize].next;
+        steps = 0;
+        while f != F_HEAD {
+            if f >= nf || steps > nf {
+                defects.push(MeshDefect::OpenList { element: MeshElement::Face });
+                break;
+            }
+            let face = &self.faces[f as usize];
+            if face.next >= nf || self.faces[face.next as usize].prev != f {
+                defects.push(MeshDefect::BrokenList { element: MeshElement::Face, index: f });
+            }
+            report.faces += 1;
+            self.check_ring(MeshElement::Face, f, face.an_edge, &usable, &mut by_face, defects);
+            f = face.next;
+            steps += 1;
+        }
+        for h in 0..ne {
+            let faceless = usable[h as usize] && self.edges[h as usize].lface == INVALID;
+            if usable[h as usize] && !(by_org[h as usize] && (by_face[h as usize] || faceless)) {
+                defects.push(MeshDefect::Unreached { edge: h });
+            }
+        }
+        report
+    }
+
+    /// Walk the `onext` ring of vertex `index` or the `lnext` ring of face
+    /// `index` from `an_edge`, checking that each edge belongs to it.
+    fn check_ring(
+        &self,
+        element: MeshElement,
+        index: u32,
+        an_edge: EdgeIdx,
+        usable: &[bool],
+        seen: &mut [bool],
+        defects: &mut Vec<MeshDefect>,
+    ) {
+        if an_edge as usize >= usable.len() || !usable[an_edge as usize] {
+            defects.push(MeshDefect::BadIndex { element, index, field: "an_edge" });
+            return;
+        }
+        let mut e = an_edge;
+        for _ in 0..usable.len() {
+            let he = &self.edges[e as usize];
+            let (found, next) = match element {
+                MeshElement::Vertex => (he.org, he.onext),
+                _ => (he.lface, he.lnext),
+            };
+            if found != index {
+                defects.push(match element {
+                    MeshElement::Vertex => MeshDefect::WrongOrigin { edge: e, expected: index, found },
+                    _ => MeshDefect::WrongFace { edge: e, expected: index, found },
+                });
+                return;
+            }
+            seen[e as usize] = true;
+            if next == an_edge {
+                return;
+            }
+            if !usable[next as usize] || seen[next as usize] {
+                break;
+            }
+            e = next;
+        }
+        defects.push(MeshDefect::OpenRing { element, index });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two triangles sharing an edge.
+    fn diamond() -> Mesh {
+        let mut mesh = Mesh::new();
+        let a = mesh.make_edge().unwrap();
+        let b = mesh.add_edge_vertex(a).unwrap();
+        let c = mesh.connect(b, a).unwrap();
+        mesh.add_edge_vertex(c ^ 1).unwrap();
+        let d = mesh.edges[(c ^ 1) as usize].lnext;
+        mesh.connect(d, c ^ 1).unwrap();
+        mesh
+    }
+
+    #[test]
+    fn valid_meshes_pass() {
+        assert!(Mesh::new().check().is_ok());
+        let mesh = diamond();
+        let report = mesh.check();
+        assert!(report.is_ok(), "{}", report);
+        assert_eq!((report.vertices, report.faces, report.edges), (4, 3, 5));
+    }
+
+    #[test]
+    fn broken_lnext_is_reported() {
+        let mut mesh = diamond();
+        let e = mesh.edges[E_HEAD as usize].next;
+        let ln = mesh.edges[e as usize].lnext;
+        mesh.edges[e as usize].lnext = mesh.edges[ln as usize].lnext;
+        let report = mesh.check();
+        assert!(report.defects.contains(&MeshDefect::SpliceMismatch { edge: e }), "{}", report);
+    }
+
+    #[test]
+    fn invalid_links_are_reported_without_panicking() {
+        let mut mesh = diamond();
+        let e = mesh.edges[E_HEAD as usize].next;
+        mesh.edges[e as usize].onext = INVALID;
+        mesh.edges[(e ^ 1) as usize].org = 7;
+        let report = mesh.check();
+        assert!(report.defects.contains(&MeshDefect::BadIndex {
+            element: MeshElement::Edge,
+            index: e,
+            field: "onext"
+        }));
+        assert!(report.defects.contains(&MeshDefect::BadIndex {
+            element: MeshElement::Edge,
+            index: e ^ 1,
+            field: "org"
+        }));
+    }
+
+    #[test]
+    fn wrong_face_and_dead_vertex_are_reported() {
+        let mut mesh = diamond();
+        let f = mesh.faces[F_HEAD as usize].next;
+        let e = mesh.faces[f as usize].an_edge;
+        let other = mesh.faces[f as usize].next;
+        mesh.edges[e as usize].lface = other;
+        let v = mesh.verts[V_HEAD as usize].next;
+        mesh.verts[v as usize].next = INVALID;
+        let report = mesh.check();
+        assert!(report
+            .defects
+            .iter()
+            .any(|d| matches!(d, MeshDefect::WrongFace { edge, .. } if *edge == e)));
+        assert!(report
+            .defects
+            .iter()
+            .any(|d| matches!(d, MeshDefect::BrokenList { element: MeshElement::Vertex, .. })));
+    }
+}
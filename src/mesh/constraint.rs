@@ -0,0 +1,203 @@
+// Copyright 2025 Lars Brubaker
+// Insertion of open constraint polylines into the inside triangulation.
+//
+// Each segment is first cut wherever it crosses an existing constrained
+// edge (region boundary or an earlier constraint), so the pieces between
+// cuts lie entirely inside or entirely outside the filled area.  Inside
+// pieces are then forced into the triangulation by flipping away the edges
+// they cross (Sloan's method) and flagged `constrained`, which keeps
+// `refine_delaunay`, quality refinement and `merge_convex_faces` from
+// removing them.  Winding numbers and `inside` flags are untouched.
+
+use std::collections::VecDeque;
+
+use super::insert::SteinerPoint;
+use super::refine::orient;
+use super::{EdgeIdx, Mesh, VertIdx, E_HEAD, INVALID};
+use crate::geom::Real;
+
+impl Mesh {
+    /// Edge `from -> to`, if the two vertices are connected.
+    fn edge_between(&self, from: VertIdx, to: VertIdx) -> Option<EdgeIdx> {
+        let start = self.verts[from as usize].an_edge;
+        let mut e = start;
+        loop {
+            if self.dst(e) == to {
+                return Some(e);
+            }
+            e = self.edges[e as usize].onext;
+            if e == start {
+                return None;
+            }
+        }
+    }
+
+    /// Walk the straight line from `from` towards `to`.  Returns the first
+    /// vertex the line reaches (`to`, or a vertex lying exactly on the line)
+    /// and leaves the unconstrained edges crossed on the way there in
+    /// `crossed`.
+    fn trace_segment(&self, from: VertIdx, to: VertIdx, crossed: &mut VecDeque<EdgeIdx>) -> Option<VertIdx> {
+        let (pu, pw) = (self.st(from), self.st(to));
+        let side = |v: VertIdx| orient(pu, pw, self.st(v));
+        let ahead = |v: VertIdx| {
+            let p = self.st(v);
+            (p[0] - pu[0]) * (pw[0] - pu[0]) + (p[1] - pu[1]) * (pw[1] - pu[1]) > 0.0
+        };
+
+        // Find the triangle at `from` whose corner the line leaves through.
+        crossed.clear();
+        let start = self.verts[from as usize].an_edge;
+        let mut e = start;
+        loop {
+            let x = self.dst(e);
+            if x == to || (side(x) == 0.0 && ahead(x)) {
+                return Some(x);
+            }
+            if self.face_is_inside(self.edges[e as usize].lface) {
+                let opposite = self.edges[e as usize].lnext;
+                let y = self.dst(opposite);
+                if side(x) < 0.0 && side(y) > 0.0 {
+                    crossed.push_back(opposite);
+                    break;
+                }
+            }
+            e = self.edges[e as usize].onext;
+            if e == start {
+                return None;
+            }
+        }
+
+        for _ in 0..self.edges.len() {
+            let last = *crossed.back().unwrap();
+            if self.edge_is_constrained(last) {
+                return None;
+            }
+            let s = last ^ 1;
+            let s1 = self.edges[s as usize].lnext;
+            let s2 = self.edges[s1 as usize].lnext;
+            let z = self.edges[s2 as usize].org;
+            if z == to || side(z) == 0.0 {
+                return Some(z);
+            }
+            let b = self.dst(s);
+            crossed.push_back(if (side(z) > 0.0) == (side(b) > 0.0) { s2 } else { s1 });
+        }
+        None
+    }
+
+    /// Make `from -> to` an edge of the triangulation and flag it
+    /// constrained, stopping at intermediate vertices on the line.
+    pub(super) fn force_edge(&mut self, from: VertIdx, to: VertIdx) -> bool {
+        self.force_edge_with(from, to, &mut VecDeque::new())
+    }
+
+    /// `force_edge` using `queue` as its work list.
+    pub(super) fn force_edge_with(&mut self, from: VertIdx, to: VertIdx, queue: &mut VecDeque<EdgeIdx>) -> bool {
+        let mut from = from;
+        while from != to {
+            let Some(next) = self.trace_segment(from, to, queue) else {
+                return false;
+            };
+            let (pu, pn) = (self.st(from), self.st(next));
+            let crosses = |mesh: &Mesh, e: EdgeIdx| {
+                let p = mesh.st(mesh.edges[e as usize].org);
+                let q = mesh.st(mesh.dst(e));
+                orient(pu, pn, p) * orient(pu, pn, q) < 0.0
+            };
+            let mut budget = 4 * queue.len() * queue.len() + 16;
+            while let Some(e) = queue.pop_front() {
+                if budget == 0 {
+                    return false;
+                }
+                budget -= 1;
+                let a = self.st(self.edges[e as usize].org);
+                let b = self.st(self.dst(e));
+                let c = self.st(self.edges[self.lprev(e) as usize].org);
+                let d = self.st(self.edges[self.lprev(e ^ 1) as usize].org);
+                if orient(c, d, a) * orient(c, d, b) < 0.0 {
+                    self.flip_edge(e);
+                    if crosses(self, e) {
+                        queue.push_back(e);
+                    }
+                } else {
+                    queue.push_back(e);
+                }
+            }
+            let Some(e) = self.edge_between(from, next) else {
+                return false;
+            };
+            self.edges[e as usize].constrained = true;
+            self.edges[(e ^ 1) as usize].constrained = true;
+            from = next;
+        }
+        true
+    }
+
+    /// Insert one constraint segment.  Returns `false` if part of the
+    /// segment inside the filled area could not be inserted.
+    fn insert_constraint_segment(&mut self, a: &SteinerPoint, b: &SteinerPoint) -> bool {
+        let (pa, pb) = (a.0, b.0);
+        if pa == pb {
+            return true;
+        }
+
+        // Cut the constrained edges the segment crosses properly.
+        let mut cuts: Vec<(Real, EdgeIdx, Real)> = Vec::new();
+        let mut e = self.edges[E_HEAD as usize].next;
+        while e != E_HEAD {
+            let touches_inside = self.face_is_inside(self.edges[e as usize].lface)
+                || self.face_is_inside(self.rface(e));
+            if touches_inside && self.edge_is_constrained(e) {
+                let p = self.st(self.edges[e as usize].org);
+                let q = self.st(self.dst(e));
+                let (d1, d2) = (orient(pa, pb, p), orient(pa, pb, q));
+                let (d3, d4) = (orient(p, q, pa), orient(p, q, pb));
+                if d1 * d2 < 0.0 && d3 * d4 < 0.0 {
+                    cuts.push((d3 / (d3 - d4), e, d1 / (d1 - d2)));
+                }
+            }
+            e = self.edges[e as usize].next;
+        }
+        let mut stops: Vec<(Real, VertIdx)> = Vec::new();
+        for (t, e, u) in cuts {
+            match self.split_at(e, u) {
+                Some(v) => stops.push((t, v)),
+                None => return false,
+            }
+        }
+
+        for (t, point) in [(0.0, a), (1.0, b)] {
+            if let Some((v, created)) = self.vertex_at(INVALID, point.0) {
+                if created {
+                    self.claim_vertex(v, point);
+                }
+                stops.push((t, v));
+            }
+        }
+        stops.sort_by(|x, y| x.0.total_cmp(&y.0));
+        stops.dedup_by_key(|s| s.1);
+
+        let mut ok = true;
+        for pair in stops.windows(2) {
+            let ((t0, v0), (t1, v1)) = (pair[0], pair[1]);
+            let t = 0.5 * (t0 + t1);
+            let mid = [pa[0] + (pb[0] - pa[0]) * t, pa[1] + (pb[1] - pa[1]) * t];
+            if self.contains_point(mid) {
+                ok &= self.force_edge(v0, v1);
+            }
+        }
+        ok
+    }
+
+    /// Insert an open polyline whose segments must appear as edges of the
+    /// inside triangulation.  Parts outside the filled area are ignored;
+    /// crossings with boundaries and earlier constraints become vertices.
+    /// Returns `false` if some inside part could not be inserted.
+    pub fn insert_constraint(&mut self, polyline: &[SteinerPoint]) -> bool {
+        let mut ok = true;
+        for pair in polyline.windows(2) {
+            ok &= self.insert_constraint_segment(&pair[0], &pair[1]);
+        }
+        ok
+    }
+}
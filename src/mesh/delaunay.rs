@@ -60,8 +60,15 @@ impl Mesh {
     }
 
     /// Refine a valid triangulation into a Constrained Delaunay Triangulation.
+    /// Boundary edges and `constrained` constraint edges are never flipped.
     pub fn refine_delaunay(&mut self) {
-        let mut stack: Vec<EdgeIdx> = Vec::new();
+        self.refine_delaunay_with(&mut Vec::new());
+    }
+
+    /// `refine_delaunay`, keeping its edge stack in `stack` so repeated
+    /// calls stop allocating once warmed up.
+    pub fn refine_delaunay_with(&mut self, stack: &mut Vec<EdgeIdx>) {
+        stack.clear();
 
         let mut f = self.faces[F_HEAD as usize].next;
         while f != F_HEAD {
@@ -69,7 +76,7 @@ impl Mesh {
                 let e_start = self.faces[f as usize].an_edge;
                 let mut e = e_start;
                 loop {
-                    let is_internal = self.edge_is_internal(e);
+                    let is_internal = self.edge_is_internal(e) && !self.edges[e as usize].constrained;
                     self.edges[e as usize].mark = is_internal;
                     if is_internal && !self.edges[(e ^ 1) as usize].mark {
                         stack.push(e);
@@ -103,7 +110,10 @@ impl Mesh {
                 ];
                 self.flip_edge(e);
                 for &nb in &neighbors {
-                    if !self.edges[nb as usize].mark && self.edge_is_internal(nb) {
+                    if !self.edges[nb as usize].mark
+                        && self.edge_is_internal(nb)
+                        && !self.edges[nb as usize].constrained
+                    {
                         self.edges[nb as usize].mark = true;
                         self.edges[(nb ^ 1) as usize].mark = true;
                         stack.push(nb);
@@ -0,0 +1,127 @@
+// Copyright 2025 Lars Brubaker
+// Insertion of caller-supplied Steiner points into the inside triangles.
+//
+// Runs after `tessellate_interior` (the monotone decomposition needs the
+// original contour vertices only) and before `refine_delaunay`, which
+// restores the Delaunay property around the new vertices when CDT output
+// is requested.
+
+use super::refine::{dist2, orient, Location};
+use super::{EdgeIdx, FaceIdx, Mesh, VertIdx, F_HEAD, INVALID};
+use crate::geom::Real;
+
+/// A point to insert: projected (s, t), original coordinates, and the
+/// caller-visible vertex index.
+pub type SteinerPoint = ([Real; 2], [Real; 3], u32);
+
+/// Where a point landed when searching every inside triangle.
+enum Hit {
+    Face(FaceIdx),
+    Edge(EdgeIdx),
+    Vertex(VertIdx),
+}
+
+impl Mesh {
+    /// Brute-force search of the inside triangles, used when the walk from
+    /// the previous insertion is blocked by a boundary.
+    fn find_point(&self, p: [Real; 2]) -> Option<Hit> {
+        let mut f = self.faces[F_HEAD as usize].next;
+        while f != F_HEAD {
+            if self.face_is_inside(f) {
+                let es = self.triangle(f);
+                let mut on_edge = None;
+                let mut outside = false;
+                for e in es {
+                    let a = self.st(self.edges[e as usize].org);
+                    let b = self.st(self.dst(e));
+                    if dist2(a, p) <= 1e-24 * dist2(a, b) {
+                        return Some(Hit::Vertex(self.edges[e as usize].org));
+                    }
+                    let o = orient(a, b, p);
+                    if o < -1e-12 * dist2(a, b) {
+                        outside = true;
+                        break;
+                    }
+                    if o <= 1e-12 * dist2(a, b) {
+                        on_edge = Some(e);
+                    }
+                }
+                if !outside {
+                    return Some(on_edge.map_or(Hit::Face(f), Hit::Edge));
+                }
+            }
+            f = self.faces[f as usize].next;
+        }
+        None
+    }
+
+    /// Is `p` inside (or on the boundary of) some inside triangle?
+    pub(super) fn contains_point(&self, p: [Real; 2]) -> bool {
+        self.find_point(p).is_some()
+    }
+
+    /// The vertex at `p`, inserting one into the inside triangle (or onto
+    /// the edge) under it if needed.  `hint` is a face to start walking
+    /// from.  Returns the vertex and whether it was created, or `None` when
+    /// `p` is outside every inside face.
+    pub(super) fn vertex_at(&mut self, hint: FaceIdx, p: [Real; 2]) -> Option<(VertIdx, bool)> {
+        let walked = if self.face_is_inside(hint) { self.locate(hint, p) } else { None };
+        let hit = match walked {
+            Some(Location::Face(f)) => Hit::Face(f),
+            Some(Location::Edge(e)) => Hit::Edge(e),
+            Some(Location::Blocked(_)) | None => self.find_point(p)?,
+        };
+        match hit {
+            Hit::Vertex(v) => Some((v, false)),
+            Hit::Face(f) => {
+                for e in self.triangle(f) {
+                    let v = self.edges[e as usize].org;
+                    if self.st(v) == p {
+                        return Some((v, false));
+                    }
+                }
+                Some((self.insert_in_face(f, p)?, true))
+            }
+            Hit::Edge(e) => {
+                let a = self.st(self.edges[e as usize].org);
+                let b = self.st(self.dst(e));
+                let u = ((p[0] - a[0]) * (b[0] - a[0]) + (p[1] - a[1]) * (b[1] - a[1])) / dist2(a, b);
+                if u <= 0.0 {
+                    Some((self.edges[e as usize].org, false))
+                } else if u >= 1.0 {
+                    Some((self.dst(e), false))
+                } else {
+                    Some((self.split_at(e, u)?, true))
+                }
+            }
+        }
+    }
+
+    /// Give a vertex created by `vertex_at` its caller-supplied data.
+    pub(super) fn claim_vertex(&mut self, v: VertIdx, point: &SteinerPoint) {
+        self.place_vertex(v, point.0, point.1);
+        self.verts[v as usize].idx = point.2;
+    }
+
+    /// Insert isolated points as vertices of the inside triangulation.
+    /// Points outside every inside face, or on top of an existing vertex,
+    /// are skipped.  With `legalize`, unconstrained edges around each new
+    /// vertex are flipped back to Delaunay as it goes (incremental
+    /// Delaunay insertion).  Returns the number of points inserted.
+    pub fn insert_points(&mut self, points: &[SteinerPoint], legalize: bool) -> usize {
+        let mut hint = INVALID;
+        let mut inserted = 0;
+        for point in points {
+            let Some((v, true)) = self.vertex_at(hint, point.0) else {
+                continue;
+            };
+            self.claim_vertex(v, point);
+            if legalize {
+                self.legalize_around(v);
+            }
+            hint = self.edges[self.verts[v as usize].an_edge as usize].lface;
+            inserted += 1;
+        }
+        inserted
+    }
+}
@@ -15,9 +15,21 @@
 //     - faces[0] = fHead (dummy face)
 //     - edges[0] = eHead, edges[1] = eHeadSym (dummy edge pair)
 
+mod check;
+mod constraint;
 mod delaunay;
+mod insert;
+mod partition;
+mod query;
+mod refine;
+mod voronoi;
 
-use crate::geom::{vert_ccw, Real};
+pub use check::{MeshDefect, MeshElement, MeshReport};
+pub use insert::SteinerPoint;
+pub use partition::PartitionScratch;
+pub use voronoi::VoronoiCells;
+
+use crate::geom::Real;
 
 pub const INVALID: u32 = u32::MAX;
 
@@ -107,6 +119,9 @@ pub struct HalfEdge {
     pub winding: i32,
     /// Used by edge flip (Delaunay refinement).
     pub mark: bool,
+    /// Part of a caller constraint polyline: never flipped or merged away.
+    /// Always set on both halves of the pair.
+    pub constrained: bool,
 }
 
 impl Default for HalfEdge {
@@ -120,15 +135,19 @@ impl Default for HalfEdge {
             active_region: INVALID,
             winding: 0,
             mark: false,
+            constrained: false,
         }
     }
 }
 
 /// The half-edge mesh.
+#[derive(Clone)]
 pub struct Mesh {
     pub verts: Vec<Vertex>,
     pub faces: Vec<Face>,
     pub edges: Vec<HalfEdge>,
+    /// Decide orientation tests exactly (`TessOption::ExactPredicates`).
+    pub exact_predicates: bool,
 }
 
 // ──────────────────────────────── Sentinel indices ────────────────────────────
@@ -144,7 +163,19 @@ impl Mesh {
             verts: Vec::new(),
             faces: Vec::new(),
             edges: Vec::new(),
+            exact_predicates: false,
         };
+        m.clear();
+        m
+    }
+
+    /// Empty the mesh back to its sentinel nodes, keeping the arenas'
+    /// capacity.
+    pub fn clear(&mut self) {
+        let m = self;
+        m.verts.clear();
+        m.faces.clear();
+        m.edges.clear();
 
         // vHead (index 0) -- dummy vertex
         let mut v_head = Vertex::default();
@@ -184,8 +215,6 @@ impl Mesh {
 
         m.edges.push(e_head);
         m.edges.push(e_head_sym);
-
-        m
     }
 
     // ──────────────── Navigation helpers (C macro translations) ────────────────
@@ -709,6 +738,9 @@ impl Mesh {
         let e_org_sym_winding = self.edges[e_org_sym as usize].winding;
         self.edges[e_new as usize].winding = e_org_winding;
         self.edges[(e_new ^ 1) as usize].winding = e_org_sym_winding;
+        let constrained = self.edges[e_org as usize].constrained;
+        self.edges[e_new as usize].constrained = constrained;
+        self.edges[(e_new ^ 1) as usize].constrained = constrained;
 
         Some(e_new)
     }
@@ -860,6 +892,7 @@ impl Mesh {
                 || !self.faces[e_lface as usize].inside
                 || e_sym_lface == INVALID
                 || !self.faces[e_sym_lface as usize].inside
+                || self.edges[e as usize].constrained
             {
                 e = e_next;
                 continue;
@@ -872,32 +905,7 @@ impl Mesh {
                 continue;
             }
 
-            // Check convexity: va--vb--vc and vd--ve--vf must be CCW
-            let va = self.edges[self.lprev(e) as usize].org;
-            let vb = self.edges[e as usize].org;
-            let vc_edge = self.edges[e_sym as usize].lnext;
-            let vc = self.dst(vc_edge);
-
-            let vd = self.edges[self.lprev(e_sym) as usize].org;
-            let ve = self.edges[e_sym as usize].org;
-            let vf_edge = self.edges[e as usize].lnext;
-            let vf = self.dst(vf_edge);
-
-            let convex = vert_ccw(
-                self.verts[va as usize].s,
-                self.verts[va as usize].t,
-                self.verts[vb as usize].s,
-                self.verts[vb as usize].t,
-                self.verts[vc as usize].s,
-                self.verts[vc as usize].t,
-            ) && vert_ccw(
-                self.verts[vd as usize].s,
-                self.verts[vd as usize].t,
-                self.verts[ve as usize].s,
-                self.verts[ve as usize].t,
-                self.verts[vf as usize].s,
-                self.verts[vf as usize].t,
-            );
+            let convex = self.removal_keeps_convex(e);
 
             if convex {
                 let actual_next = if e == e_next || e == e_next ^ 1 {
@@ -935,14 +943,14 @@ impl Mesh {
         let fb = self.edges[b0 as usize].lface;
 
         self.edges[a0 as usize].org = b_opp;
-        self.edges[a0 as usize].onext = self.edges[b1 as usize].onext ^ 1; // b1->Sym
+        self.edges[a0 as usize].onext = b1 ^ 1;
         self.edges[b0 as usize].org = a_opp;
-        self.edges[b0 as usize].onext = self.edges[a1 as usize].onext ^ 1; // a1->Sym
+        self.edges[b0 as usize].onext = a1 ^ 1;
         self.edges[a2 as usize].onext = b0;
         self.edges[b2 as usize].onext = a0;
-        self.edges[b1 as usize].onext = self.edges[a2 as usize].onext ^ 1; // a2->Sym... wait
+        self.edges[b1 as usize].onext = a2 ^ 1;
+        self.edges[a1 as usize].onext = b2 ^ 1;
 
-        // Redo using correct flip logic from C code:
         self.edges[a0 as usize].lnext = a2;
         self.edges[a2 as usize].lnext = b1;
         self.edges[b1 as usize].lnext = a0;
@@ -1058,4 +1066,34 @@ mod tests {
         assert_ne!(second, INVALID);
     }
 
+    #[test]
+    fn flip_edge_keeps_the_mesh_valid() {
+        // Two triangles sharing the edge `c`.
+        let mut mesh = Mesh::new();
+        let a = mesh.make_edge().unwrap();
+        let b = mesh.add_edge_vertex(a).unwrap();
+        let c = mesh.connect(b, a).unwrap();
+        mesh.add_edge_vertex(c ^ 1).unwrap();
+        let d = mesh.edges[(c ^ 1) as usize].lnext;
+        mesh.connect(d, c ^ 1).unwrap();
+        let ends = |mesh: &Mesh| {
+            let (o, d) = (mesh.edges[c as usize].org, mesh.dst(c));
+            (o.min(d), o.max(d))
+        };
+        let before = ends(&mesh);
+
+        mesh.flip_edge(c);
+        let report = mesh.check();
+        assert!(report.is_ok(), "{}", report);
+        assert_ne!(ends(&mesh), before);
+        for e in [c, c ^ 1] {
+            assert_eq!(mesh.count_face_verts(mesh.edges[e as usize].lface), 3);
+        }
+
+        mesh.flip_edge(c);
+        let report = mesh.check();
+        assert!(report.is_ok(), "{}", report);
+        assert_eq!(ends(&mesh), before);
+    }
+
 }
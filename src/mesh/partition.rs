@@ -0,0 +1,234 @@
+// Copyright 2025 Lars Brubaker
+// Convex partition of the inside faces.
+//
+// Hertel–Mehlhorn deletes every diagonal of the triangulation whose
+// removal leaves both endpoints convex, and keeps at most two diagonals per
+// reflex corner.  How close it gets to the optimum depends on the
+// triangulation it starts from and the order diagonals are visited in, so
+// three variants run and the one with the fewest pieces wins:
+//   - edge list order (what `merge_convex_faces` does without a size cap);
+//   - diagonals touching no reflex corner first, then longest first;
+//   - as above, after pairing reflex corners that see each other inside
+//     both of their resolving cones (shortest pairs first) and forcing the
+//     diagonal between them, so one diagonal fixes two corners.
+// Removing an edge only ever widens the angles at its endpoints, so a
+// diagonal that must stay never becomes removable later and one pass per
+// variant suffices.  Every variant keeps Hertel–Mehlhorn's bound of at most
+// four times the optimum.
+
+use std::collections::VecDeque;
+
+use super::refine::{dist2, orient};
+use super::{EdgeIdx, Mesh, VertIdx, E_HEAD, F_HEAD, INVALID, V_HEAD};
+use crate::geom::{vert_ccw, vert_ccw_exact, Real};
+
+/// Reflex corners beyond this count are not paired up: the pairing looks at
+/// every pair of corners.
+const MAX_PAIRED_REFLEX: usize = 4096;
+
+/// A reflex corner of the fill and the (s, t) of its boundary neighbours.
+struct Corner {
+    v: VertIdx,
+    prev: [Real; 2],
+    next: [Real; 2],
+}
+
+impl Mesh {
+    /// Would deleting `e` (separating two inside faces) leave the merged
+    /// face convex at both endpoints of `e`?
+    pub(super) fn removal_keeps_convex(&self, e: EdgeIdx) -> bool {
+        let e_sym = e ^ 1;
+        let ccw = if self.exact_predicates { vert_ccw_exact } else { vert_ccw };
+        let corner = |a: EdgeIdx, b: EdgeIdx, c: EdgeIdx| {
+            let (a, b, c) = (
+                &self.verts[self.edges[a as usize].org as usize],
+                &self.verts[self.edges[b as usize].org as usize],
+                &self.verts[self.dst(c) as usize],
+            );
+            ccw(a.s, a.t, b.s, b.t, c.s, c.t)
+        };
+        corner(self.lprev(e), e, self.edges[e_sym as usize].lnext)
+            && corner(self.lprev(e_sym), e_sym, self.edges[e as usize].lnext)
+    }
+
+    /// `v` as a reflex corner: a boundary vertex with a single fan of
+    /// inside triangles spanning more than a half turn.
+    fn reflex_corner(&self, v: VertIdx) -> Option<Corner> {
+        let inside = |e: EdgeIdx| self.face_is_inside(self.edges[e as usize].lface);
+        let first = self.verts[v as usize].an_edge;
+        let mut start = INVALID;
+        let mut e = first;
+        loop {
+            if inside(e) && !self.face_is_inside(self.rface(e)) {
+                if start != INVALID {
+                    return None;
+                }
+                start = e;
+            }
+            e = self.edges[e as usize].onext;
+            if e == first {
+                break;
+            }
+        }
+        if start == INVALID {
+            return None;
+        }
+        let p = self.st(v);
+        let mut angle = 0.0;
+        let mut e = start;
+        let last = loop {
+            let n = self.edges[e as usize].onext;
+            let (a, b) = (self.st(self.dst(e)), self.st(self.dst(n)));
+            let (u, w) = ([a[0] - p[0], a[1] - p[1]], [b[0] - p[0], b[1] - p[1]]);
+            angle += (u[0] * w[1] - u[1] * w[0]).atan2(u[0] * w[0] + u[1] * w[1]);
+            if !inside(n) {
+                break n;
+            }
+            e = n;
+        };
+        (angle > std::f64::consts::PI as Real + 1e-9).then(|| Corner {
+            v,
+            prev: self.st(self.dst(last)),
+            next: self.st(self.dst(start)),
+        })
+    }
+
+    /// Force diagonals between pairs of reflex corners that fix both ends.
+    fn pair_reflex_corners(&mut self, scratch: &mut PartitionScratch) {
+        let corners = &scratch.corners;
+        let resolves = |c: &Corner, o: [Real; 2], p: [Real; 2]| {
+            orient(o, c.next, p) >= 0.0 && orient(o, p, c.prev) >= 0.0
+        };
+        let pairs = &mut scratch.pairs;
+        pairs.clear();
+        for i in 0..corners.len() {
+            let pi = self.st(corners[i].v);
+            for j in i + 1..corners.len() {
+                let pj = self.st(corners[j].v);
+                if resolves(&corners[i], pi, pj) && resolves(&corners[j], pj, pi) {
+                    pairs.push((dist2(pi, pj), i, j));
+                }
+            }
+        }
+        pairs.sort_by(|x, y| x.0.total_cmp(&y.0));
+        let used = &mut scratch.used;
+        used.clear();
+        used.resize(corners.len(), false);
+        for &(_, i, j) in pairs.iter() {
+            if !used[i] && !used[j] && self.force_edge_with(corners[i].v, corners[j].v, &mut scratch.crossed) {
+                used[i] = true;
+                used[j] = true;
+            }
+        }
+    }
+
+    /// Delete every removable diagonal.  With `reflex` flags, diagonals
+    /// touching fewer reflex corners go first, then longer ones; without,
+    /// edge list order is kept.
+    fn hertel_mehlhorn(&mut self, reflex: Option<&[bool]>, diagonals: &mut Vec<(EdgeIdx, usize, Real)>) -> bool {
+        diagonals.clear();
+        let mut e = self.edges[E_HEAD as usize].next;
+        while e != E_HEAD {
+            if !self.edge_is_constrained(e) {
+                let (o, d) = (self.edges[e as usize].org, self.dst(e));
+                let touches = reflex.map_or(0, |r| r[o as usize] as usize + r[d as usize] as usize);
+                let len2 = if reflex.is_some() { dist2(self.st(o), self.st(d)) } else { 0.0 };
+                diagonals.push((e, touches, len2));
+            }
+            e = self.edges[e as usize].next;
+        }
+        diagonals.sort_by(|x, y| x.1.cmp(&y.1).then(y.2.total_cmp(&x.2)));
+
+        for &(e, _, _) in diagonals.iter() {
+            let (lf, rf) = (self.edges[e as usize].lface, self.rface(e));
+            if lf == rf || lf == INVALID || rf == INVALID || !self.removal_keeps_convex(e) {
+                continue;
+            }
+            if !self.delete_edge(e) {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn inside_face_count(&self) -> usize {
+        let mut n = 0;
+        let mut f = self.faces[F_HEAD as usize].next;
+        while f != F_HEAD {
+            n += self.faces[f as usize].inside as usize;
+            f = self.faces[f as usize].next;
+        }
+        n
+    }
+
+    /// Make `self` a copy of `other`, reusing `self`'s arenas.
+    fn copy_from(&mut self, other: &Mesh) {
+        self.verts.clone_from(&other.verts);
+        self.faces.clone_from(&other.faces);
+        self.edges.clone_from(&other.edges);
+        self.exact_predicates = other.exact_predicates;
+    }
+
+    /// Merge the inside triangles into convex polygons of any size.
+    /// Boundary and constraint edges are kept.
+    pub fn partition_convex(&mut self) -> bool {
+        self.partition_convex_with(&mut PartitionScratch::default())
+    }
+
+    /// `partition_convex`, keeping its working buffers and candidate meshes
+    /// in `scratch` so repeated calls stop allocating once warmed up.
+    pub fn partition_convex_with(&mut self, scratch: &mut PartitionScratch) -> bool {
+        scratch.corners.clear();
+        let mut v = self.verts[V_HEAD as usize].next;
+        while v != V_HEAD {
+            scratch.corners.extend(self.reflex_corner(v));
+            v = self.verts[v as usize].next;
+        }
+        scratch.reflex.clear();
+        scratch.reflex.resize(self.verts.len(), false);
+        for c in &scratch.corners {
+            scratch.reflex[c.v as usize] = true;
+        }
+
+        scratch.candidates.resize_with(2, Mesh::default);
+        let PartitionScratch { candidates, reflex, diagonals, .. } = scratch;
+        for candidate in candidates.iter_mut() {
+            candidate.copy_from(self);
+        }
+        if !candidates[0].hertel_mehlhorn(None, diagonals) || !candidates[1].hertel_mehlhorn(Some(reflex), diagonals) {
+            return false;
+        }
+        let counts = [candidates[0].inside_face_count(), candidates[1].inside_face_count()];
+        let best = if counts[1] < counts[0] { 1 } else { 0 };
+        if scratch.corners.len() <= MAX_PAIRED_REFLEX {
+            scratch.constrained.clear();
+            scratch.constrained.extend(self.edges.iter().map(|e| e.constrained));
+            self.pair_reflex_corners(scratch);
+            if !self.hertel_mehlhorn(Some(&scratch.reflex), &mut scratch.diagonals) {
+                return false;
+            }
+            // The paired diagonals were only pinned for the pass above.
+            for (edge, &was) in self.edges.iter_mut().zip(&scratch.constrained) {
+                edge.constrained = was;
+            }
+            if self.inside_face_count() < counts[best] {
+                return true;
+            }
+        }
+        std::mem::swap(self, &mut scratch.candidates[best]);
+        true
+    }
+}
+
+/// Working buffers of `Mesh::partition_convex_with`.
+#[derive(Default)]
+pub struct PartitionScratch {
+    candidates: Vec<Mesh>,
+    corners: Vec<Corner>,
+    reflex: Vec<bool>,
+    constrained: Vec<bool>,
+    diagonals: Vec<(EdgeIdx, usize, Real)>,
+    pairs: Vec<(Real, usize, usize)>,
+    used: Vec<bool>,
+    crossed: VecDeque<EdgeIdx>,
+}
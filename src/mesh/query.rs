@@ -0,0 +1,128 @@
+// Copyright 2025 Lars Brubaker
+// Read-only topology queries: element iterators, face loops, edge
+// neighbours and vertex one-rings.
+//
+// Meant for callers working on a finished mesh (see
+// `Tessellator::mesh`).  Every walk follows the lists and rings the
+// invariants guarantee (see `Mesh::check`), so the iterators only yield
+// live elements.
+
+use super::{EdgeIdx, FaceIdx, Mesh, VertIdx, E_HEAD, F_HEAD, INVALID, V_HEAD};
+
+/// Walk a ring from `start` with `step` until it comes back.
+fn ring<'a>(start: EdgeIdx, step: impl Fn(EdgeIdx) -> EdgeIdx + 'a) -> impl Iterator<Item = EdgeIdx> + 'a {
+    let mut e = start;
+    let mut done = start == INVALID;
+    std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        let current = e;
+        e = step(e);
+        done = e == start;
+        Some(current)
+    })
+}
+
+impl Mesh {
+    /// Live vertices, in vertex-list order.
+    pub fn vertex_ids(&self) -> impl Iterator<Item = VertIdx> + '_ {
+        let mut v = V_HEAD;
+        std::iter::from_fn(move || {
+            v = self.verts[v as usize].next;
+            (v != V_HEAD).then_some(v)
+        })
+    }
+
+    /// Live faces, inside and outside, in face-list order.
+    pub fn face_ids(&self) -> impl Iterator<Item = FaceIdx> + '_ {
+        let mut f = F_HEAD;
+        std::iter::from_fn(move || {
+            f = self.faces[f as usize].next;
+            (f != F_HEAD).then_some(f)
+        })
+    }
+
+    /// Faces inside the fill (the ones the tessellator outputs).
+    pub fn inside_faces(&self) -> impl Iterator<Item = FaceIdx> + '_ {
+        self.face_ids().filter(|&f| self.faces[f as usize].inside)
+    }
+
+    /// Live edges, one half-edge per pair (the even one).
+    pub fn edge_ids(&self) -> impl Iterator<Item = EdgeIdx> + '_ {
+        let mut e = E_HEAD;
+        std::iter::from_fn(move || {
+            e = self.edges[e as usize].next;
+            (e != E_HEAD).then_some(e)
+        })
+    }
+
+    /// The half-edges around face `f`, in `lnext` order (counter-clockwise
+    /// in (s, t) for inside faces), each with `f` on its left.
+    pub fn face_loop(&self, f: FaceIdx) -> impl Iterator<Item = EdgeIdx> + '_ {
+        ring(self.faces[f as usize].an_edge, |e| self.edges[e as usize].lnext)
+    }
+
+    /// The corners of face `f`, in `face_loop` order.
+    pub fn face_vertices(&self, f: FaceIdx) -> impl Iterator<Item = VertIdx> + '_ {
+        self.face_loop(f).map(|e| self.edges[e as usize].org)
+    }
+
+    /// The inside face across each edge of `face_loop(f)`, or `None` where
+    /// the edge is on the border of the fill.
+    pub fn face_neighbors(&self, f: FaceIdx) -> impl Iterator<Item = Option<FaceIdx>> + '_ {
+        self.face_loop(f).map(|e| Some(self.rface(e)).filter(|&g| self.face_is_inside(g)))
+    }
+
+    /// The faces on the left and right of half-edge `e`; `None` for a side
+    /// whose face was discarded.
+    pub fn edge_faces(&self, e: EdgeIdx) -> (Option<FaceIdx>, Option<FaceIdx>) {
+        let face = |f: FaceIdx| (f != INVALID).then_some(f);
+        (face(self.edges[e as usize].lface), face(self.rface(e)))
+    }
+
+    /// The half-edges leaving vertex `v`, in `onext` order
+    /// (counter-clockwise in (s, t)).
+    pub fn vertex_edges(&self, v: VertIdx) -> impl Iterator<Item = EdgeIdx> + '_ {
+        ring(self.verts[v as usize].an_edge, |e| self.edges[e as usize].onext)
+    }
+
+    /// The vertices joined to `v` by an edge (its one-ring), in
+    /// `vertex_edges` order.
+    pub fn one_ring(&self, v: VertIdx) -> impl Iterator<Item = VertIdx> + '_ {
+        self.vertex_edges(v).map(|e| self.dst(e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn triangle_loops_and_rings() {
+        let mut mesh = Mesh::new();
+        let a = mesh.make_edge().unwrap();
+        let b = mesh.add_edge_vertex(a).unwrap();
+        let c = mesh.connect(b, a).unwrap();
+        assert_eq!(mesh.vertex_ids().count(), 3);
+        assert_eq!(mesh.edge_ids().count(), 3);
+        assert_eq!(mesh.face_ids().count(), 2);
+
+        let (left, right) = mesh.edge_faces(c);
+        let (left, right) = (left.unwrap(), right.unwrap());
+        assert_ne!(left, right);
+        let loop_edges: Vec<EdgeIdx> = mesh.face_loop(left).collect();
+        assert_eq!(loop_edges.len(), 3);
+        assert!(loop_edges.iter().all(|&e| mesh.edges[e as usize].lface == left));
+        mesh.faces[right as usize].inside = true;
+        assert!(mesh.face_neighbors(left).all(|n| n == Some(right)));
+        mesh.faces[right as usize].inside = false;
+        assert!(mesh.face_neighbors(left).all(|n| n.is_none()));
+
+        for v in mesh.vertex_ids() {
+            let ring: Vec<VertIdx> = mesh.one_ring(v).collect();
+            assert_eq!(ring.len(), 2);
+            assert!(!ring.contains(&v));
+        }
+    }
+}
@@ -0,0 +1,404 @@
+// Copyright 2025 Lars Brubaker
+// Quality refinement (Ruppert / Chew) for Mesh.
+//
+// Runs on a constrained Delaunay triangulation of the inside faces and
+// inserts Steiner points until every triangle meets a minimum-angle bound
+// and an optional maximum area:
+//   - a segment whose diametral circle holds the apex of an adjacent inside
+//     triangle is split at its midpoint;
+//   - any other bad triangle gets its circumcenter inserted, unless the
+//     circumcenter lies across or encroaches a segment, which is split
+//     instead.
+// Segments ("constrained edges") are the edges with an outside face on
+// either side, plus constraint polyline edges.  They are never flipped, so
+// the inside/outside boundary — and with it `edge_flags` — is preserved.
+// New vertices keep `idx == INVALID` and get 3D coordinates interpolated
+// from the triangle or segment they were placed in.
+
+use super::{EdgeIdx, FaceIdx, Mesh, VertIdx, E_HEAD, F_HEAD, INVALID};
+use crate::geom::Real;
+
+/// Upper bound on Steiner points inserted by one `refine_quality` call.
+/// Angle bounds above ~20.7° are not guaranteed to terminate, and input
+/// angles smaller than the bound can never be fixed, so the pass gives up
+/// here instead of running forever.
+const MAX_REFINE_POINTS: usize = 1 << 20;
+
+/// Edges shorter than this fraction of the inside bounding-box diagonal are
+/// left alone: their circumcenters are no longer representable reliably.
+const MIN_FEATURE: Real = 1e-8;
+
+/// Twice the signed area of (a, b, c); positive when counter-clockwise.
+pub(super) fn orient(a: [Real; 2], b: [Real; 2], c: [Real; 2]) -> Real {
+    (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+}
+
+pub(super) fn dist2(a: [Real; 2], b: [Real; 2]) -> Real {
+    (a[0] - b[0]) * (a[0] - b[0]) + (a[1] - b[1]) * (a[1] - b[1])
+}
+
+fn circumcenter(a: [Real; 2], b: [Real; 2], c: [Real; 2]) -> [Real; 2] {
+    let (bx, by) = (b[0] - a[0], b[1] - a[1]);
+    let (cx, cy) = (c[0] - a[0], c[1] - a[1]);
+    let d = 2.0 * (bx * cy - by * cx);
+    let (b2, c2) = (bx * bx + by * by, cx * cx + cy * cy);
+    [a[0] + (cy * b2 - by * c2) / d, a[1] + (bx * c2 - cx * b2) / d]
+}
+
+/// `true` when `p` lies strictly inside the circle with diameter (a, b).
+fn encroaches(a: [Real; 2], b: [Real; 2], p: [Real; 2]) -> bool {
+    (a[0] - p[0]) * (b[0] - p[0]) + (a[1] - p[1]) * (b[1] - p[1]) < 0.0
+}
+
+/// Result of walking the triangulation towards a point.
+#[derive(Copy, Clone)]
+pub(super) enum Location {
+    Face(FaceIdx),
+    Edge(EdgeIdx),
+    Blocked(EdgeIdx),
+}
+
+impl Mesh {
+    /// An edge refinement must not flip or cross: it separates an inside
+    /// face from an outside one, or belongs to a constraint polyline.
+    pub fn edge_is_constrained(&self, e: EdgeIdx) -> bool {
+        self.edges[e as usize].constrained
+            || !(self.edge_is_internal(e) && self.edge_is_internal(e ^ 1))
+    }
+
+    pub(super) fn st(&self, v: VertIdx) -> [Real; 2] {
+        [self.verts[v as usize].s, self.verts[v as usize].t]
+    }
+
+    pub(super) fn face_is_inside(&self, f: FaceIdx) -> bool {
+        f != INVALID && self.faces[f as usize].inside
+    }
+
+    /// The three half-edges of an inside triangle, starting at `an_edge`.
+    pub(super) fn triangle(&self, f: FaceIdx) -> [EdgeIdx; 3] {
+        let e0 = self.faces[f as usize].an_edge;
+        let e1 = self.edges[e0 as usize].lnext;
+        [e0, e1, self.edges[e1 as usize].lnext]
+    }
+
+    fn segment_is_encroached(&self, e: EdgeIdx, min_len2: Real) -> bool {
+        let a = self.st(self.edges[e as usize].org);
+        let b = self.st(self.dst(e));
+        if dist2(a, b) < min_len2 {
+            return false;
+        }
+        [e, e ^ 1].iter().any(|&side| {
+            self.face_is_inside(self.edges[side as usize].lface)
+                && encroaches(a, b, self.st(self.edges[self.lprev(side) as usize].org))
+        })
+    }
+
+    /// Circumcenter of `f` if it violates the angle or area bound.
+    /// `max_ratio` bounds circumradius / shortest edge; `max_area == 0`
+    /// disables the area test.
+    fn bad_triangle_center(
+        &self,
+        f: FaceIdx,
+        max_ratio: Real,
+        max_area: Real,
+        min_len2: Real,
+    ) -> Option<[Real; 2]> {
+        let es = self.triangle(f);
+        if self.edges[es[2] as usize].lnext != es[0] {
+            return None;
+        }
+        let p = es.map(|e| self.st(self.edges[e as usize].org));
+        let area = 0.5 * orient(p[0], p[1], p[2]);
+        if area <= 0.0 {
+            return None;
+        }
+        // l2[i] is the squared length of edge es[i] = p[i] -> p[i + 1].
+        let l2 = [dist2(p[0], p[1]), dist2(p[1], p[2]), dist2(p[2], p[0])];
+        let shortest = (0..3).min_by(|&i, &j| l2[i].total_cmp(&l2[j])).unwrap();
+        if l2[shortest] < min_len2 {
+            return None;
+        }
+        let too_big = max_area > 0.0 && area > max_area;
+        let radius = (l2[0] * l2[1] * l2[2]).sqrt() / (4.0 * area);
+        // The smallest angle sits opposite the shortest edge; if both edges
+        // meeting there are segments the angle came from the input.
+        let input_angle = self.edge_is_constrained(es[(shortest + 1) % 3])
+            && self.edge_is_constrained(es[(shortest + 2) % 3]);
+        let too_sharp = !input_angle && radius > max_ratio * l2[shortest].sqrt();
+        if too_big || too_sharp {
+            Some(circumcenter(p[0], p[1], p[2]))
+        } else {
+            None
+        }
+    }
+
+    /// Walk from `f` towards `p`, stopping at the face containing it, at an
+    /// unconstrained edge it lies on, or at the first segment in the way.
+    pub(super) fn locate(&self, mut f: FaceIdx, p: [Real; 2]) -> Option<Location> {
+        for _ in 0..self.faces.len() + 1 {
+            let mut next = None;
+            let mut on_edge = None;
+            for e in self.triangle(f) {
+                let a = self.st(self.edges[e as usize].org);
+                let b = self.st(self.dst(e));
+                let o = orient(a, b, p);
+                if o < 0.0 {
+                    next = Some(e);
+                    break;
+                }
+                if o <= 1e-12 * dist2(a, b) {
+                    on_edge = Some(e);
+                }
+            }
+            match next {
+                Some(e) if self.edge_is_constrained(e) => return Some(Location::Blocked(e)),
+                Some(e) => f = self.rface(e),
+                None => {
+                    return Some(match on_edge {
+                        Some(e) if self.edge_is_constrained(e) => Location::Blocked(e),
+                        Some(e) => Location::Edge(e),
+                        None => Location::Face(f),
+                    })
+                }
+            }
+        }
+        None
+    }
+
+    pub(super) fn place_vertex(&mut self, v: VertIdx, st: [Real; 2], coords: [Real; 3]) {
+        let vert = &mut self.verts[v as usize];
+        vert.s = st[0];
+        vert.t = st[1];
+        vert.coords = coords;
+    }
+
+    /// Triangulate the face left of `e_in` by connecting `Dst(e_in)` to
+    /// every other vertex of the loop.
+    fn fan_vertex(&mut self, e_in: EdgeIdx) -> Option<()> {
+        loop {
+            let e1 = self.edges[e_in as usize].lnext;
+            let e2 = self.edges[e1 as usize].lnext;
+            let e3 = self.edges[e2 as usize].lnext;
+            if e3 == e_in {
+                return Some(());
+            }
+            self.connect(e_in, e3)?;
+        }
+    }
+
+    /// Split `e` at parameter `u` (0 = Org, 1 = Dst) and re-triangulate the
+    /// inside faces on either side.
+    pub(super) fn split_at(&mut self, e: EdgeIdx, u: Real) -> Option<VertIdx> {
+        let a = self.edges[e as usize].org;
+        let b = self.dst(e);
+        let e_new = self.split_edge(e)?;
+        let v = self.edges[e_new as usize].org;
+        let (pa, pb) = (self.st(a), self.st(b));
+        let (ca, cb) = (self.verts[a as usize].coords, self.verts[b as usize].coords);
+        let lerp = |x: Real, y: Real| x + (y - x) * u;
+        self.place_vertex(
+            v,
+            [lerp(pa[0], pb[0]), lerp(pa[1], pb[1])],
+            [lerp(ca[0], cb[0]), lerp(ca[1], cb[1]), lerp(ca[2], cb[2])],
+        );
+        for side in [e, e_new ^ 1] {
+            if self.face_is_inside(self.edges[side as usize].lface) {
+                self.fan_vertex(side)?;
+            }
+        }
+        Some(v)
+    }
+
+    /// Insert `p` strictly inside triangle `f` and connect it to the corners.
+    pub(super) fn insert_in_face(&mut self, f: FaceIdx, p: [Real; 2]) -> Option<VertIdx> {
+        let es = self.triangle(f);
+        let corners = es.map(|e| self.edges[e as usize].org);
+        let st = corners.map(|v| self.st(v));
+        let total = orient(st[0], st[1], st[2]);
+        let weights = [
+            orient(st[1], st[2], p) / total,
+            orient(st[2], st[0], p) / total,
+            orient(st[0], st[1], p) / total,
+        ];
+        let mut coords = [0.0; 3];
+        for (v, w) in corners.iter().zip(weights) {
+            for (c, x) in coords.iter_mut().zip(self.verts[*v as usize].coords) {
+                *c += w * x;
+            }
+        }
+        let e_new = self.add_edge_vertex(es[0])?;
+        let v = self.dst(e_new);
+        self.place_vertex(v, p, coords);
+        self.fan_vertex(e_new)?;
+        Some(v)
+    }
+
+    /// Restore the Delaunay property around a freshly inserted vertex by
+    /// flipping unconstrained edges opposite it (Lawson).
+    pub(super) fn legalize_around(&mut self, v: VertIdx) {
+        let mut stack = Vec::new();
+        let start = self.verts[v as usize].an_edge;
+        let mut e = start;
+        loop {
+            if self.face_is_inside(self.edges[e as usize].lface) {
+                stack.push(self.edges[e as usize].lnext);
+            }
+            e = self.edges[e as usize].onext;
+            if e == start {
+                break;
+            }
+        }
+        let max_iter = 64 * self.edges.len();
+        let mut iter = 0;
+        while let Some(e) = stack.pop() {
+            iter += 1;
+            if iter > max_iter {
+                break;
+            }
+            if self.edge_is_constrained(e) || self.edge_is_locally_delaunay(e) {
+                continue;
+            }
+            let far1 = self.edges[(e ^ 1) as usize].lnext;
+            let far2 = self.edges[far1 as usize].lnext;
+            self.flip_edge(e);
+            stack.push(far1);
+            stack.push(far2);
+        }
+    }
+
+    /// Queue the inside faces around `v` and the segments bounding them.
+    fn queue_around(&self, v: VertIdx, segments: &mut Vec<EdgeIdx>, faces: &mut Vec<FaceIdx>) {
+        let start = self.verts[v as usize].an_edge;
+        let mut e = start;
+        loop {
+            let f = self.edges[e as usize].lface;
+            if self.face_is_inside(f) {
+                faces.push(f);
+                for s in self.triangle(f) {
+                    if self.edge_is_constrained(s) {
+                        segments.push(s);
+                    }
+                }
+            }
+            e = self.edges[e as usize].onext;
+            if e == start {
+                break;
+            }
+        }
+    }
+
+    /// First segment among the edges of `f` and its neighbours whose
+    /// diametral circle contains `p`.
+    fn encroached_segment_near(&self, f: FaceIdx, p: [Real; 2]) -> Option<EdgeIdx> {
+        let mut faces = vec![f];
+        for e in self.triangle(f) {
+            let r = self.rface(e);
+            if self.face_is_inside(r) {
+                faces.push(r);
+            }
+        }
+        faces.into_iter().flat_map(|g| self.triangle(g)).find(|&e| {
+            self.edge_is_constrained(e)
+                && encroaches(self.st(self.edges[e as usize].org), self.st(self.dst(e)), p)
+        })
+    }
+
+    /// Delaunay refinement of the inside triangles (Ruppert / Chew).
+    ///
+    /// `min_angle` is in degrees (0 disables the angle bound); `max_area`
+    /// bounds triangle area in the (s, t) projection plane (0 disables it).
+    /// Expects a constrained Delaunay triangulation, i.e. `refine_delaunay`
+    /// has already run.  Returns `false` only if a mesh operation failed.
+    pub fn refine_quality(&mut self, min_angle: Real, max_area: Real) -> bool {
+        let max_ratio = if min_angle > 0.0 {
+            1.0 / (2.0 * min_angle.min(60.0).to_radians().sin())
+        } else {
+            Real::INFINITY
+        };
+
+        let mut faces = Vec::new();
+        let (mut lo, mut hi) = ([Real::MAX; 2], [Real::MIN; 2]);
+        let mut f = self.faces[F_HEAD as usize].next;
+        while f != F_HEAD {
+            if self.faces[f as usize].inside {
+                faces.push(f);
+                for e in self.triangle(f) {
+                    let p = self.st(self.edges[e as usize].org);
+                    for i in 0..2 {
+                        lo[i] = lo[i].min(p[i]);
+                        hi[i] = hi[i].max(p[i]);
+                    }
+                }
+            }
+            f = self.faces[f as usize].next;
+        }
+        if faces.is_empty() {
+            return true;
+        }
+        let min_len2 = dist2(lo, hi) * MIN_FEATURE * MIN_FEATURE;
+
+        let mut segments = Vec::new();
+        let mut e = self.edges[E_HEAD as usize].next;
+        while e != E_HEAD {
+            let touches_inside = self.face_is_inside(self.edges[e as usize].lface)
+                || self.face_is_inside(self.rface(e));
+            if touches_inside && self.edge_is_constrained(e) {
+                segments.push(e);
+            }
+            e = self.edges[e as usize].next;
+        }
+
+        let mut inserted = 0;
+        while inserted < MAX_REFINE_POINTS {
+            // Encroached segments first: they may be the cause of bad
+            // triangles, and circumcenters must never land outside.
+            let v = if let Some(s) = segments.pop() {
+                if !self.segment_is_encroached(s, min_len2) {
+                    continue;
+                }
+                self.split_at(s, 0.5)
+            } else if let Some(f) = faces.pop() {
+                let Some(c) = self.bad_triangle_center(f, max_ratio, max_area, min_len2) else {
+                    continue;
+                };
+                let Some(location) = self.locate(f, c) else {
+                    continue;
+                };
+                let split = match location {
+                    Location::Blocked(s) => Some(s),
+                    Location::Face(g) => self.encroached_segment_near(g, c),
+                    Location::Edge(e) => self.encroached_segment_near(self.edges[e as usize].lface, c),
+                };
+                match (split, location) {
+                    (Some(s), _) => {
+                        let a = self.st(self.edges[s as usize].org);
+                        if dist2(a, self.st(self.dst(s))) < min_len2 {
+                            continue;
+                        }
+                        // Retry `f` once the segment is out of the way.
+                        faces.push(f);
+                        self.split_at(s, 0.5)
+                    }
+                    (None, Location::Edge(e)) => {
+                        let a = self.st(self.edges[e as usize].org);
+                        let b = self.st(self.dst(e));
+                        let u = ((c[0] - a[0]) * (b[0] - a[0]) + (c[1] - a[1]) * (b[1] - a[1]))
+                            / dist2(a, b);
+                        self.split_at(e, u.clamp(0.0, 1.0))
+                    }
+                    (None, Location::Face(g)) => self.insert_in_face(g, c),
+                    (None, Location::Blocked(_)) => continue,
+                }
+            } else {
+                break;
+            };
+            let Some(v) = v else {
+                return false;
+            };
+            inserted += 1;
+            self.legalize_around(v);
+            self.queue_around(v, &mut segments, &mut faces);
+        }
+        true
+    }
+}
@@ -7,7 +7,8 @@ impl Mesh {
     /// Tessellate a single monotone region (face).
     /// The face must be a CCW-oriented simple polygon.
     pub fn tessellate_mono_region(&mut self, face: super::FaceIdx) -> bool {
-        use crate::geom::{edge_sign, vert_leq};
+        use crate::geom::{edge_sign_fn, vert_leq};
+        let edge_sign = edge_sign_fn(self.exact_predicates);
 
         let mut up = match self.face_edge(face) {
             Some(edge) => edge,
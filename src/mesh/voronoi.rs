@@ -0,0 +1,237 @@
+// Copyright 2025 Lars Brubaker
+// Voronoi cells dual to the inside triangulation.
+//
+// Every vertex of the inside triangulation is a site; its cell is the
+// polygon through the circumcenters of the triangles around it, walked
+// counter-clockwise in (s, t).  Cells are clipped to the filled area:
+//   - a boundary vertex is a corner of its own cell, and the boundary edges
+//     leaving and entering it contribute their midpoints;
+//   - a constraint edge contributes its midpoint, so cells stop at it;
+//   - a circumcenter that cannot be reached from its triangle without
+//     crossing a boundary or constraint edge is pulled back to the midpoint
+//     of the triangle's longest edge.
+// The cells tile the filled area.  On a Delaunay triangulation they are the
+// Voronoi cells of the vertices wherever no clamping was needed.
+
+use super::refine::{dist2, orient};
+use super::{EdgeIdx, FaceIdx, Mesh, VertIdx, F_HEAD, INVALID, V_HEAD};
+use crate::geom::Real;
+
+/// Cells of every site, sharing one point list.
+#[derive(Clone, Debug, Default)]
+pub struct VoronoiCells {
+    /// Cell corners in input coordinates.
+    pub points: Vec<[Real; 3]>,
+    /// `(site, start, count)`: the cell of vertex `site` is
+    /// `indices[start..start + count]`, counter-clockwise in (s, t).
+    pub cells: Vec<(VertIdx, usize, usize)>,
+    pub indices: Vec<u32>,
+    /// Point ids already created for a face, edge or vertex (`INVALID` if
+    /// not).
+    face: Vec<(u32, bool)>,
+    edge: Vec<u32>,
+    vert: Vec<u32>,
+    /// The cell being deduplicated by `fan_cell`.
+    cell: Vec<u32>,
+}
+
+impl VoronoiCells {
+    fn push(&mut self, coords: [Real; 3]) -> u32 {
+        self.points.push(coords);
+        (self.points.len() - 1) as u32
+    }
+}
+
+fn circumcenter(p: [[Real; 2]; 3]) -> [Real; 2] {
+    let (bx, by) = (p[1][0] - p[0][0], p[1][1] - p[0][1]);
+    let (cx, cy) = (p[2][0] - p[0][0], p[2][1] - p[0][1]);
+    let d = 2.0 * (bx * cy - by * cx);
+    let (b2, c2) = (bx * bx + by * by, cx * cx + cy * cy);
+    [p[0][0] + (cy * b2 - by * c2) / d, p[0][1] + (bx * c2 - cx * b2) / d]
+}
+
+fn inside_triangle(p: [[Real; 2]; 3], c: [Real; 2]) -> bool {
+    (0..3).all(|i| orient(p[i], p[(i + 1) % 3], c) >= 0.0)
+}
+
+impl Mesh {
+    /// Does the straight line from the centroid of `f` to `c` stay inside
+    /// the fill without crossing a boundary or constraint edge?
+    fn sees(&self, mut f: FaceIdx, c: [Real; 2]) -> bool {
+        let p = self.corners(f);
+        let from = [(p[0][0] + p[1][0] + p[2][0]) / 3.0, (p[0][1] + p[1][1] + p[2][1]) / 3.0];
+        let mut entry = INVALID;
+        for _ in 0..self.faces.len() {
+            if inside_triangle(self.corners(f), c) {
+                return true;
+            }
+            let exit = self.triangle(f).into_iter().find(|&e| {
+                let a = self.st(self.edges[e as usize].org);
+                let b = self.st(self.dst(e));
+                e != entry && orient(a, b, c) < 0.0 && orient(from, c, a) * orient(from, c, b) <= 0.0
+            });
+            match exit {
+                Some(e) if !self.edge_is_constrained(e) => {
+                    f = self.rface(e);
+                    entry = e ^ 1;
+                }
+                _ => return false,
+            }
+        }
+        false
+    }
+
+    fn corners(&self, f: FaceIdx) -> [[Real; 2]; 3] {
+        self.triangle(f).map(|e| self.st(self.edges[e as usize].org))
+    }
+
+    /// Midpoint of `e`, shared by both halves.
+    fn edge_point(&self, pts: &mut VoronoiCells, e: EdgeIdx) -> u32 {
+        let slot = (e >> 1) as usize;
+        if pts.edge[slot] == INVALID {
+            let a = self.verts[self.edges[e as usize].org as usize].coords;
+            let b = self.verts[self.dst(e) as usize].coords;
+            pts.edge[slot] = pts.push([0, 1, 2].map(|i| 0.5 * (a[i] + b[i])));
+        }
+        pts.edge[slot]
+    }
+
+    /// The Voronoi vertex of inside triangle `f`, and whether it had to be
+    /// clamped to the midpoint of the longest edge.
+    fn face_point(&self, pts: &mut VoronoiCells, f: FaceIdx) -> (u32, bool) {
+        if pts.face[f as usize].0 != INVALID {
+            return pts.face[f as usize];
+        }
+        let es = self.triangle(f);
+        let p = self.corners(f);
+        let area = orient(p[0], p[1], p[2]);
+        // es[i] runs p[i] -> p[i + 1].
+        let l2 = [0, 1, 2].map(|i| dist2(p[i], p[(i + 1) % 3]));
+        let longest = (0..3).max_by(|&i, &j| l2[i].total_cmp(&l2[j])).unwrap();
+        let c = circumcenter(p);
+        let usable = area > 0.0 && self.sees(f, c);
+        let entry = if usable {
+            // Affine extension of the triangle's (s, t) -> xyz map.
+            let w = [0, 1, 2].map(|i| orient(p[(i + 1) % 3], p[(i + 2) % 3], c) / area);
+            let mut coords = [0.0; 3];
+            for (i, e) in es.iter().enumerate() {
+                let x = self.verts[self.edges[*e as usize].org as usize].coords;
+                for k in 0..3 {
+                    coords[k] += w[i] * x[k];
+                }
+            }
+            (pts.push(coords), false)
+        } else {
+            (self.edge_point(pts, es[longest]), true)
+        };
+        pts.face[f as usize] = entry;
+        entry
+    }
+
+    fn vert_point(&self, pts: &mut VoronoiCells, v: VertIdx) -> u32 {
+        if pts.vert[v as usize] == INVALID {
+            pts.vert[v as usize] = pts.push(self.verts[v as usize].coords);
+        }
+        pts.vert[v as usize]
+    }
+
+    /// Append the cell of `v` for the fan of inside triangles starting at
+    /// the outgoing edge `start` (counter-clockwise).  `open` is set when
+    /// the fan is bounded by outside faces rather than closing on itself.
+    fn fan_cell(&self, pts: &mut VoronoiCells, v: VertIdx, start: EdgeIdx, open: bool) {
+        let first = pts.indices.len();
+        if open {
+            let corner = self.vert_point(pts, v);
+            pts.indices.push(corner);
+            let mid = self.edge_point(pts, start);
+            pts.indices.push(mid);
+        }
+        let mut e = start;
+        loop {
+            let f = self.edges[e as usize].lface;
+            let (point, clamped) = self.face_point(pts, f);
+            pts.indices.push(point);
+            let next = self.edges[e as usize].onext;
+            let next_f = self.edges[next as usize].lface;
+            if !self.face_is_inside(next_f) {
+                let mid = self.edge_point(pts, next);
+                pts.indices.push(mid);
+                break;
+            }
+            let (_, next_clamped) = self.face_point(pts, next_f);
+            if clamped || next_clamped || self.edges[next as usize].constrained {
+                let mid = self.edge_point(pts, next);
+                pts.indices.push(mid);
+            }
+            if next == start {
+                break;
+            }
+            e = next;
+        }
+        // Clamped points coincide with edge midpoints; drop the repeats.
+        let cell = &mut pts.cell;
+        cell.clear();
+        cell.extend(pts.indices.drain(first..));
+        cell.dedup();
+        if cell.len() > 1 && cell.first() == cell.last() {
+            cell.pop();
+        }
+        if cell.len() >= 3 {
+            pts.cells.push((v, first, cell.len()));
+            pts.indices.extend_from_slice(cell);
+        }
+    }
+
+    /// Voronoi cells of every vertex of the inside triangulation, clipped to
+    /// the filled area.  Expects every inside face to be a triangle.  A
+    /// vertex where separate fans of inside triangles meet gets one cell
+    /// per fan.
+    pub fn voronoi_cells(&self) -> VoronoiCells {
+        let mut cells = VoronoiCells::default();
+        self.voronoi_cells_with(&mut cells);
+        cells
+    }
+
+    /// `voronoi_cells` into `pts`, replacing its cells but keeping its
+    /// buffers, so repeated calls stop allocating once warmed up.
+    pub fn voronoi_cells_with(&self, pts: &mut VoronoiCells) {
+        pts.points.clear();
+        pts.cells.clear();
+        pts.indices.clear();
+        pts.face.clear();
+        pts.face.resize(self.faces.len(), (INVALID, false));
+        pts.edge.clear();
+        pts.edge.resize(self.edges.len() / 2, INVALID);
+        pts.vert.clear();
+        pts.vert.resize(self.verts.len(), INVALID);
+        let mut f = self.faces[F_HEAD as usize].next;
+        while f != F_HEAD {
+            if self.face_is_inside(f) {
+                self.face_point(pts, f);
+            }
+            f = self.faces[f as usize].next;
+        }
+
+        let mut v = self.verts[V_HEAD as usize].next;
+        while v != V_HEAD {
+            let e0 = self.verts[v as usize].an_edge;
+            let mut e = e0;
+            let mut closed = true;
+            loop {
+                let inside = self.face_is_inside(self.edges[e as usize].lface);
+                if inside && !self.face_is_inside(self.rface(e)) {
+                    self.fan_cell(pts, v, e, true);
+                }
+                closed &= inside;
+                e = self.edges[e as usize].onext;
+                if e == e0 {
+                    break;
+                }
+            }
+            if closed {
+                self.fan_cell(pts, v, e0, false);
+            }
+            v = self.verts[v as usize].next;
+        }
+    }
+}
@@ -0,0 +1,274 @@
+// Copyright 2025 Lars Brubaker
+// License: SGI Free Software License B (MIT-compatible)
+//
+// Navigation mesh over `ElementType::ConnectedPolygons` output.
+//
+// The polygons of a walkable area are the nodes of the dual graph and the
+// edges they share are the portals between them.  A path query locates the
+// polygons holding both end points by walking across portals, runs A* over
+// the dual graph to find a corridor of polygons, and pulls a string through
+// the corridor's portals with the "simple stupid funnel" algorithm
+// (Mononen), which yields the shortest path inside the corridor.
+//
+// A* works on portal midpoints: a polygon is entered at the midpoint of the
+// portal crossed to reach it, and costs are straight-line distances between
+// those points.  This is the usual navmesh approximation; the corridor it
+// picks is not always the one holding the globally shortest path, but the
+// path through it is exact.
+//
+// Only x and y of each vertex are used.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::geom::Real;
+use crate::tess::TESS_UNDEF;
+
+/// `(b - a) × (c - a)`: positive when `c` is left of `a`→`b`.
+fn cross(a: [Real; 2], b: [Real; 2], c: [Real; 2]) -> Real {
+    (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+}
+
+fn distance(a: [Real; 2], b: [Real; 2]) -> Real {
+    (a[0] - b[0]).hypot(a[1] - b[1])
+}
+
+/// An A* queue entry, ordered so `BinaryHeap` pops the lowest estimate.
+struct Open {
+    estimate: Real,
+    poly: usize,
+}
+
+impl PartialEq for Open {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Open {}
+
+impl PartialOrd for Open {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Open {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.total_cmp(&self.estimate).then(other.poly.cmp(&self.poly))
+    }
+}
+
+/// Convex polygons with shared-edge adjacency, built from
+/// `ConnectedPolygons` output.
+#[derive(Clone, Debug)]
+pub struct NavMesh {
+    vertices: Vec<[Real; 2]>,
+    /// Corner vertex indices of each polygon.
+    polys: Vec<Vec<u32>>,
+    /// Neighbour across the edge from corner `i` to corner `i + 1`, or
+    /// `TESS_UNDEF` on the border.
+    neighbors: Vec<Vec<u32>>,
+    /// `1.0` when the polygons wind counter-clockwise in x-y, else `-1.0`.
+    winding: Real,
+}
+
+impl NavMesh {
+    /// Build from `ConnectedPolygons` output: `vertices()` with
+    /// `vertex_size` components each, and `elements()` as written for
+    /// `poly_size`.
+    pub fn new(vertices: &[Real], vertex_size: usize, elements: &[u32], poly_size: usize) -> Self {
+        let vertices: Vec<[Real; 2]> = vertices.chunks_exact(vertex_size).map(|v| [v[0], v[1]]).collect();
+        let mut polys = Vec::new();
+        let mut neighbors = Vec::new();
+        for element in elements.chunks_exact(poly_size * 2) {
+            let (corners, across) = element.split_at(poly_size);
+            let count = corners.iter().take_while(|&&v| v != TESS_UNDEF).count();
+            polys.push(corners[..count].to_vec());
+            neighbors.push(across[..count].to_vec());
+        }
+        let mut area = 0.0;
+        for poly in &polys {
+            for (i, &v) in poly.iter().enumerate() {
+                let (a, b) = (vertices[v as usize], vertices[poly[(i + 1) % poly.len()] as usize]);
+                area += a[0] * b[1] - b[0] * a[1];
+            }
+        }
+        let winding = if area < 0.0 { -1.0 } else { 1.0 };
+        NavMesh { vertices, polys, neighbors, winding }
+    }
+
+    pub fn polygon_count(&self) -> usize {
+        self.polys.len()
+    }
+
+    /// x and y of vertex `v`.
+    pub fn vertex(&self, v: u32) -> [Real; 2] {
+        self.vertices[v as usize]
+    }
+
+    /// Corner vertex indices of polygon `poly`.
+    pub fn polygon(&self, poly: usize) -> &[u32] {
+        &self.polys[poly]
+    }
+
+    /// Polygons sharing an edge with `poly`.
+    pub fn neighbors(&self, poly: usize) -> impl Iterator<Item = usize> + '_ {
+        self.neighbors[poly].iter().filter(|&&n| n != TESS_UNDEF).map(|&n| n as usize)
+    }
+
+    fn corner(&self, poly: usize, i: usize) -> [Real; 2] {
+        let p = &self.polys[poly];
+        self.vertices[p[i % p.len()] as usize]
+    }
+
+    /// The first edge of `poly` that `p` lies strictly outside of.
+    fn outside_edge(&self, poly: usize, p: [Real; 2]) -> Option<usize> {
+        (0..self.polys[poly].len())
+            .find(|&i| self.winding * cross(self.corner(poly, i), self.corner(poly, i + 1), p) < 0.0)
+    }
+
+    pub fn contains(&self, poly: usize, p: [Real; 2]) -> bool {
+        self.outside_edge(poly, p).is_none()
+    }
+
+    /// The polygon containing `p`, or `None` when `p` is off the mesh.
+    /// Walks from `hint` (e.g. the polygon of the previous query) across
+    /// the edges `p` lies beyond, so nearby queries take a few steps; falls
+    /// back to testing every polygon when the walk runs into a hole.
+    pub fn locate(&self, p: [Real; 2], hint: Option<usize>) -> Option<usize> {
+        let mut poly = hint.filter(|&h| h < self.polys.len()).unwrap_or(0);
+        for _ in 0..self.polys.len() {
+            match self.outside_edge(poly, p) {
+                None => return Some(poly),
+                Some(i) => match self.neighbors[poly][i] {
+                    TESS_UNDEF => break,
+                    n => poly = n as usize,
+                },
+            }
+        }
+        (0..self.polys.len()).find(|&poly| self.contains(poly, p))
+    }
+
+    /// The portal from `poly` into its neighbour `next`, as its (left,
+    /// right) end points seen when walking across it; `None` when the two
+    /// do not share an edge.
+    fn portal(&self, poly: usize, next: usize) -> Option<([Real; 2], [Real; 2])> {
+        let i = self.neighbors.get(poly)?.iter().position(|&n| n as usize == next)?;
+        let (a, b) = (self.corner(poly, i), self.corner(poly, i + 1));
+        Some(if self.winding > 0.0 { (b, a) } else { (a, b) })
+    }
+
+    /// A* over the dual graph: the polygons from `start_poly` to
+    /// `goal_poly`, each sharing an edge with the next, or `None` when they
+    /// are not connected.
+    pub fn find_corridor(
+        &self,
+        start_poly: usize,
+        start: [Real; 2],
+        goal_poly: usize,
+        goal: [Real; 2],
+    ) -> Option<Vec<usize>> {
+        let n = self.polys.len();
+        let mut cost = vec![Real::INFINITY; n];
+        let mut entry = vec![start; n];
+        let mut parent = vec![usize::MAX; n];
+        let mut open = BinaryHeap::new();
+        cost[start_poly] = 0.0;
+        open.push(Open { estimate: distance(start, goal), poly: start_poly });
+        while let Some(Open { estimate, poly }) = open.pop() {
+            if poly == goal_poly {
+                let mut corridor = vec![poly];
+                while let Some(&last) = corridor.last().filter(|&&p| p != start_poly) {
+                    corridor.push(parent[last]);
+                }
+                corridor.reverse();
+                return Some(corridor);
+            }
+            if estimate > cost[poly] + distance(entry[poly], goal) {
+                continue;
+            }
+            for next in self.neighbors(poly) {
+                let (l, r) = self.portal(poly, next)?;
+                let mid = [0.5 * (l[0] + r[0]), 0.5 * (l[1] + r[1])];
+                let at = if next == goal_poly { goal } else { mid };
+                let g = cost[poly] + distance(entry[poly], at);
+                if g < cost[next] {
+                    cost[next] = g;
+                    entry[next] = at;
+                    parent[next] = poly;
+                    open.push(Open { estimate: g + distance(at, goal), poly: next });
+                }
+            }
+        }
+        None
+    }
+
+    /// The shortest path from `start` to `goal` through `corridor` (as
+    /// returned by `find_corridor`), starting with `start` and ending with
+    /// `goal`: the simple stupid funnel algorithm over the corridor's
+    /// portals.  `None` when two consecutive polygons of `corridor` do not
+    /// share an edge.
+    pub fn string_pull(&self, corridor: &[usize], start: [Real; 2], goal: [Real; 2]) -> Option<Vec<[Real; 2]>> {
+        let mut portals = vec![(start, start)];
+        for w in corridor.windows(2) {
+            portals.push(self.portal(w[0], w[1])?);
+        }
+        portals.push((goal, goal));
+
+        let mut path = vec![start];
+        let (mut apex, mut left, mut right) = (start, start, start);
+        let (mut left_i, mut right_i) = (0, 0);
+        let mut i = 1;
+        while i < portals.len() {
+            let (l, r) = portals[i];
+            // Narrow the funnel from the right, or turn round its left side.
+            if cross(apex, right, r) >= 0.0 {
+                if apex == right || cross(apex, left, r) < 0.0 {
+                    right = r;
+                    right_i = i;
+                } else {
+                    apex = left;
+                    if path.last() != Some(&apex) {
+                        path.push(apex);
+                    }
+                    right = apex;
+                    right_i = left_i;
+                    i = left_i + 1;
+                    continue;
+                }
+            }
+            // The same from the left.
+            if cross(apex, left, l) <= 0.0 {
+                if apex == left || cross(apex, right, l) > 0.0 {
+                    left = l;
+                    left_i = i;
+                } else {
+                    apex = right;
+                    if path.last() != Some(&apex) {
+                        path.push(apex);
+                    }
+                    left = apex;
+                    left_i = right_i;
+                    i = right_i + 1;
+                    continue;
+                }
+            }
+            i += 1;
+        }
+        if path.last() != Some(&goal) {
+            path.push(goal);
+        }
+        Some(path)
+    }
+
+    /// The shortest path from `start` to `goal` through the polygons A*
+    /// picks, as corner points from `start` to `goal`; `None` when either
+    /// point is off the mesh or they are not connected.
+    pub fn find_path(&self, start: [Real; 2], goal: [Real; 2]) -> Option<Vec<[Real; 2]>> {
+        let start_poly = self.locate(start, None)?;
+        let goal_poly = self.locate(goal, Some(start_poly))?;
+        let corridor = self.find_corridor(start_poly, start, goal_poly, goal)?;
+        self.string_pull(&corridor, start, goal)
+    }
+}
@@ -0,0 +1,295 @@
+// Copyright 2025 Lars Brubaker
+// License: SGI Free Software License B (MIT-compatible)
+//
+// Adaptive-precision geometric predicates (`TessOption::ExactPredicates`).
+//
+// Shewchuk's expansion arithmetic: a value is kept as a sum of
+// non-overlapping doubles, so sums and products of input coordinates are
+// represented without rounding.  `orient2d` first tries the plain f64
+// determinant and only falls back to longer expansions when the result is
+// within its error bound, so the common case costs a few extra compares.
+// The sign of every result is exact; the magnitude is an approximation.
+//
+// Reference: J. R. Shewchuk, "Adaptive Precision Floating-Point Arithmetic
+// and Fast Robust Geometric Predicates", 1997.
+
+use crate::geom::Real;
+
+const EPSILON: Real = Real::EPSILON * 0.5;
+const SPLITTER: Real = 134_217_729.0; // 2^27 + 1
+const RESULT_ERR_BOUND: Real = (3.0 + 8.0 * EPSILON) * EPSILON;
+const CCW_ERR_BOUND_A: Real = (3.0 + 16.0 * EPSILON) * EPSILON;
+const CCW_ERR_BOUND_B: Real = (2.0 + 12.0 * EPSILON) * EPSILON;
+const CCW_ERR_BOUND_C: Real = (9.0 + 64.0 * EPSILON) * EPSILON * EPSILON;
+
+/// `a + b` as `(sum, error)` with `sum + error` exact.
+#[inline]
+fn two_sum(a: Real, b: Real) -> (Real, Real) {
+    let x = a + b;
+    let b_virt = x - a;
+    let a_virt = x - b_virt;
+    (x, (a - a_virt) + (b - b_virt))
+}
+
+/// `a - b` as `(difference, error)` with `difference + error` exact.
+#[inline]
+fn two_diff(a: Real, b: Real) -> (Real, Real) {
+    let x = a - b;
+    (x, two_diff_tail(a, b, x))
+}
+
+#[inline]
+fn two_diff_tail(a: Real, b: Real, x: Real) -> Real {
+    let b_virt = a - x;
+    let a_virt = x + b_virt;
+    (a - a_virt) + (b_virt - b)
+}
+
+#[inline]
+fn split(a: Real) -> (Real, Real) {
+    let c = SPLITTER * a;
+    let hi = c - (c - a);
+    (hi, a - hi)
+}
+
+/// `a * b` as `(product, error)` with `product + error` exact.
+#[inline]
+fn two_product(a: Real, b: Real) -> (Real, Real) {
+    let x = a * b;
+    let (a_hi, a_lo) = split(a);
+    let (b_hi, b_lo) = split(b);
+    let err = x - a_hi * b_hi - a_lo * b_hi - a_hi * b_lo;
+    (x, a_lo * b_lo - err)
+}
+
+/// `(a1 + a0) - (b1 + b0)` as a four-component expansion, smallest first.
+fn two_two_diff(a1: Real, a0: Real, b1: Real, b0: Real) -> [Real; 4] {
+    let (i, x0) = two_diff(a0, b0);
+    let (j, k) = two_sum(a1, i);
+    let (i, x1) = two_diff(k, b1);
+    let (x3, x2) = two_sum(j, i);
+    [x0, x1, x2, x3]
+}
+
+/// Sum of two expansions (smallest component first), zero components
+/// dropped.
+fn expansion_sum(e: &[Real], f: &[Real]) -> Vec<Real> {
+    let mut h = Vec::with_capacity(e.len() + f.len());
+    let (mut ei, mut fi) = (0, 0);
+    let mut next = || {
+        if fi >= f.len() || (ei < e.len() && e[ei].abs() < f[fi].abs()) {
+            ei += 1;
+            e[ei - 1]
+        } else {
+            fi += 1;
+            f[fi - 1]
+        }
+    };
+    let total = e.len() + f.len();
+    if total == 0 {
+        return h;
+    }
+    let mut q = next();
+    for _ in 1..total {
+        let (sum, err) = two_sum(q, next());
+        if err != 0.0 {
+            h.push(err);
+        }
+        q = sum;
+    }
+    if q != 0.0 || h.is_empty() {
+        h.push(q);
+    }
+    h
+}
+
+/// Approximate value of an expansion.
+fn estimate(e: &[Real]) -> Real {
+    e.iter().sum()
+}
+
+/// Positive when `a`, `b`, `c` turn counter-clockwise, negative when
+/// clockwise, zero when collinear.  The sign is exact; the magnitude
+/// approximates twice the signed triangle area.
+pub fn orient2d(a: [Real; 2], b: [Real; 2], c: [Real; 2]) -> Real {
+    let det_left = (a[0] - c[0]) * (b[1] - c[1]);
+    let det_right = (a[1] - c[1]) * (b[0] - c[0]);
+    let det = det_left - det_right;
+    let det_sum = if det_left > 0.0 {
+        if det_right <= 0.0 {
+            return det;
+        }
+        det_left + det_right
+    } else if det_left < 0.0 {
+        if det_right >= 0.0 {
+            return det;
+        }
+        -det_left - det_right
+    } else {
+        return det;
+    };
+    let err_bound = CCW_ERR_BOUND_A * det_sum;
+    if det >= err_bound || -det >= err_bound {
+        return det;
+    }
+    orient2d_adapt(a, b, c, det_sum)
+}
+
+fn orient2d_adapt(a: [Real; 2], b: [Real; 2], c: [Real; 2], det_sum: Real) -> Real {
+    let (acx, bcx) = (a[0] - c[0], b[0] - c[0]);
+    let (acy, bcy) = (a[1] - c[1], b[1] - c[1]);
+
+    let (left, left_tail) = two_product(acx, bcy);
+    let (right, right_tail) = two_product(acy, bcx);
+    let b_exp = two_two_diff(left, left_tail, right, right_tail);
+    let mut det = estimate(&b_exp);
+    let err_bound = CCW_ERR_BOUND_B * det_sum;
+    if det >= err_bound || -det >= err_bound {
+        return det;
+    }
+
+    let acx_tail = two_diff_tail(a[0], c[0], acx);
+    let bcx_tail = two_diff_tail(b[0], c[0], bcx);
+    let acy_tail = two_diff_tail(a[1], c[1], acy);
+    let bcy_tail = two_diff_tail(b[1], c[1], bcy);
+    if acx_tail == 0.0 && acy_tail == 0.0 && bcx_tail == 0.0 && bcy_tail == 0.0 {
+        return det;
+    }
+
+    let err_bound = CCW_ERR_BOUND_C * det_sum + RESULT_ERR_BOUND * det.abs();
+    det += (acx * bcy_tail + bcy * acx_tail) - (acy * bcx_tail + bcx * acy_tail);
+    if det >= err_bound || -det >= err_bound {
+        return det;
+    }
+
+    let cross = |ax: Real, by: Real, ay: Real, bx: Real| {
+        let (s1, s0) = two_product(ax, by);
+        let (t1, t0) = two_product(ay, bx);
+        two_two_diff(s1, s0, t1, t0)
+    };
+    let c1 = expansion_sum(&b_exp, &cross(acx_tail, bcy, acy_tail, bcx));
+    let c2 = expansion_sum(&c1, &cross(acx, bcy_tail, acy, bcx_tail));
+    let d = expansion_sum(&c2, &cross(acx_tail, bcy_tail, acy_tail, bcx_tail));
+    *d.last().unwrap()
+}
+
+/// Exact expansion of `a * b` for expansions `a` and `b`.
+fn expansion_product(a: &[Real], b: &[Real]) -> Vec<Real> {
+    let mut sum = Vec::new();
+    for &x in a {
+        for &y in b {
+            let (p, err) = two_product(x, y);
+            sum = expansion_sum(&sum, &[err, p]);
+        }
+    }
+    sum
+}
+
+fn expansion_diff(a: &[Real], b: &[Real]) -> Vec<Real> {
+    let neg: Vec<Real> = b.iter().map(|x| -x).collect();
+    expansion_sum(a, &neg)
+}
+
+fn diff(a: Real, b: Real) -> [Real; 2] {
+    let (x, err) = two_diff(a, b);
+    [err, x]
+}
+
+/// Exact sign of `edge_eval(u1, v, w1) - edge_eval(u2, v, w2)`: compares
+/// the t-coordinates of edges `u1 w1` and `u2 w2` at the s-coordinate of
+/// `v`.  Each edge needs `u.s <= v.s <= w.s`; a vertical edge evaluates
+/// to zero, as in `edge_eval`.
+pub fn edge_eval_cmp(u1: [Real; 2], w1: [Real; 2], v: [Real; 2], u2: [Real; 2], w2: [Real; 2]) -> Real {
+    // edge_eval(u, v, w) = orient2d(u, w, v) / (w.s - u.s); compare the two
+    // fractions by cross-multiplying with their (non-negative) spans.
+    let num = |u: [Real; 2], w: [Real; 2]| -> (Vec<Real>, Vec<Real>) {
+        if u[0] < w[0] {
+            let det = expansion_diff(
+                &expansion_product(&diff(w[0], u[0]), &diff(v[1], u[1])),
+                &expansion_product(&diff(w[1], u[1]), &diff(v[0], u[0])),
+            );
+            let span = diff(w[0], u[0]).to_vec();
+            (det, span)
+        } else {
+            (vec![0.0], vec![1.0])
+        }
+    };
+    let (n1, d1) = num(u1, w1);
+    let (n2, d2) = num(u2, w2);
+    let x = expansion_diff(&expansion_product(&n1, &d2), &expansion_product(&n2, &d1));
+    *x.last().unwrap_or(&0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Exact orientation on coordinates that are multiples of 2^-53 below
+    /// 2^6, so every product fits an i128.
+    fn exact_sign(a: [Real; 2], b: [Real; 2], c: [Real; 2]) -> i32 {
+        let q = |x: Real| (x * (1u64 << 53) as Real) as i128;
+        let (ax, ay, bx, by, cx, cy) = (q(a[0]), q(a[1]), q(b[0]), q(b[1]), q(c[0]), q(c[1]));
+        let det = (ax - cx) * (by - cy) - (ay - cy) * (bx - cx);
+        det.signum() as i32
+    }
+
+    fn sign(x: Real) -> i32 {
+        if x > 0.0 {
+            1
+        } else if x < 0.0 {
+            -1
+        } else {
+            0
+        }
+    }
+
+    #[test]
+    fn orient2d_basic() {
+        assert!(orient2d([0.0, 0.0], [1.0, 0.0], [0.0, 1.0]) > 0.0);
+        assert!(orient2d([0.0, 0.0], [0.0, 1.0], [1.0, 0.0]) < 0.0);
+        assert_eq!(orient2d([0.0, 0.0], [1.0, 1.0], [2.0, 2.0]), 0.0);
+    }
+
+    #[test]
+    fn orient2d_near_collinear_grid() {
+        // Shewchuk's classic failure case: nudge a point near the line
+        // y = x by a few ulps and compare against exact integer arithmetic.
+        let ulp = Real::EPSILON * 0.5;
+        let mut naive_wrong = 0;
+        for i in 0..64 {
+            for j in 0..64 {
+                let a = [0.5 + i as Real * ulp, 0.5 + j as Real * ulp];
+                let (b, c) = ([12.0, 12.0], [24.0, 24.0]);
+                let expected = exact_sign(a, b, c);
+                assert_eq!(sign(orient2d(a, b, c)), expected, "i={} j={}", i, j);
+                let naive = (a[0] - c[0]) * (b[1] - c[1]) - (a[1] - c[1]) * (b[0] - c[0]);
+                naive_wrong += (sign(naive) != expected) as usize;
+            }
+        }
+        assert!(naive_wrong > 0, "grid should defeat the plain determinant");
+    }
+
+    #[test]
+    fn orient2d_is_antisymmetric() {
+        let ulp = Real::EPSILON;
+        let (a, b, c) = ([0.1 + ulp, 0.3], [0.7, 0.9 - ulp], [0.4, 0.6]);
+        let s = sign(orient2d(a, b, c));
+        assert_eq!(sign(orient2d(b, a, c)), -s);
+        assert_eq!(sign(orient2d(b, c, a)), s);
+        assert_eq!(sign(orient2d(c, a, b)), s);
+    }
+
+    #[test]
+    fn edge_eval_cmp_orders_close_edges() {
+        // Two edges through the same left point with slopes one ulp apart.
+        let v = [1.0, 0.0];
+        let u = [0.0, 0.0];
+        let w1 = [2.0, 1.0];
+        let w2 = [2.0, 1.0 + Real::EPSILON];
+        assert!(edge_eval_cmp(u, w1, v, u, w2) > 0.0);
+        assert!(edge_eval_cmp(u, w2, v, u, w1) < 0.0);
+        assert_eq!(edge_eval_cmp(u, w1, v, u, w1), 0.0);
+        // Vertical edge: evaluates to v.t.
+        assert_eq!(edge_eval_cmp([1.0, -5.0], [1.0, 5.0], v, [0.0, -1.0], [2.0, 1.0]), 0.0);
+    }
+}
@@ -0,0 +1,260 @@
+// Copyright 2025 Lars Brubaker
+// License: SGI Free Software License B (MIT-compatible)
+//
+// Section properties of the filled region: area, centroid, second moments
+// of area and bounding box, per output polygon, per connected island and in
+// total.
+//
+// Each polygon is integrated exactly with Green's theorem over its edges,
+// so the numbers do not depend on how the region was cut into triangles or
+// convex pieces.  Integrals are taken about the centre of the output's
+// bounding box and moved to each centroid at the end, which keeps far-off
+// coordinates from cancelling.  Every polygon counts positively whatever
+// its winding, so the result is the same for either orientation of the
+// output.
+//
+// Islands are the classes of polygons joined by shared edges; polygons
+// touching at a single vertex are separate islands.
+//
+// Only x and y of each vertex are used.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use crate::geom::Real;
+use crate::tess::{ElementType, TESS_UNDEF};
+
+/// Area properties of a polygon, an island or the whole fill.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct SectionProperties {
+    pub area: Real,
+    /// Area centroid; the middle of `min`..`max` when `area` is zero.
+    pub centroid: [Real; 2],
+    /// Second moments of area about axes through the centroid, as
+    /// `[Ixx, Iyy, Ixy]` with `Ixx = ∫(y - cy)² dA`, `Iyy = ∫(x - cx)² dA`
+    /// and `Ixy = ∫(x - cx)(y - cy) dA`.
+    pub second_moments: [Real; 3],
+    pub min: [Real; 2],
+    pub max: [Real; 2],
+}
+
+impl SectionProperties {
+    /// Second moments `[Ixx, Iyy, Ixy]` about axes through `p` (parallel
+    /// axis theorem).
+    pub fn moments_about(&self, p: [Real; 2]) -> [Real; 3] {
+        let (dx, dy) = (self.centroid[0] - p[0], self.centroid[1] - p[1]);
+        let [ixx, iyy, ixy] = self.second_moments;
+        [ixx + self.area * dy * dy, iyy + self.area * dx * dx, ixy + self.area * dx * dy]
+    }
+
+    /// Polar moment `Ixx + Iyy` about the centroid.
+    pub fn polar_moment(&self) -> Real {
+        self.second_moments[0] + self.second_moments[1]
+    }
+}
+
+/// Running integrals about a fixed origin.
+#[derive(Copy, Clone)]
+struct Integrals {
+    /// `∫1, ∫x, ∫y, ∫x², ∫y², ∫xy` over the area.
+    sums: [Real; 6],
+    min: [Real; 2],
+    max: [Real; 2],
+}
+
+impl Integrals {
+    const EMPTY: Integrals =
+        Integrals { sums: [0.0; 6], min: [Real::INFINITY; 2], max: [Real::NEG_INFINITY; 2] };
+
+    /// One polygon, its corners relative to the origin.
+    fn polygon(corners: &[[Real; 2]], origin: [Real; 2]) -> Self {
+        let mut it = Integrals::EMPTY;
+        let mut s = [0.0; 6];
+        for (i, &a) in corners.iter().enumerate() {
+            it.extend(a);
+            let b = corners[(i + 1) % corners.len()];
+            let (x0, y0) = (a[0] - origin[0], a[1] - origin[1]);
+            let (x1, y1) = (b[0] - origin[0], b[1] - origin[1]);
+            let c = x0 * y1 - x1 * y0;
+            s[0] += c;
+            s[1] += (x0 + x1) * c;
+            s[2] += (y0 + y1) * c;
+            s[3] += (x0 * x0 + x0 * x1 + x1 * x1) * c;
+            s[4] += (y0 * y0 + y0 * y1 + y1 * y1) * c;
+            s[5] += (x0 * y1 + 2.0 * x0 * y0 + 2.0 * x1 * y1 + x1 * y0) * c;
+        }
+        let sign = if s[0] < 0.0 { -1.0 } else { 1.0 };
+        for (sum, (s, scale)) in it.sums.iter_mut().zip(s.iter().zip([2.0, 6.0, 6.0, 12.0, 12.0, 24.0])) {
+            *sum = sign * s / scale;
+        }
+        it
+    }
+
+    fn extend(&mut self, p: [Real; 2]) {
+        self.min = [self.min[0].min(p[0]), self.min[1].min(p[1])];
+        self.max = [self.max[0].max(p[0]), self.max[1].max(p[1])];
+    }
+
+    fn add(&mut self, other: &Integrals) {
+        for k in 0..6 {
+            self.sums[k] += other.sums[k];
+        }
+        self.min = [self.min[0].min(other.min[0]), self.min[1].min(other.min[1])];
+        self.max = [self.max[0].max(other.max[0]), self.max[1].max(other.max[1])];
+    }
+
+    fn properties(&self, origin: [Real; 2]) -> SectionProperties {
+        if self.min[0] > self.max[0] {
+            return SectionProperties::default();
+        }
+        let [a, sx, sy, sxx, syy, sxy] = self.sums;
+        let mid = [0.5 * (self.min[0] + self.max[0]), 0.5 * (self.min[1] + self.max[1])];
+        if a <= 0.0 {
+            return SectionProperties { centroid: mid, min: self.min, max: self.max, ..Default::default() };
+        }
+        let (cx, cy) = (sx / a, sy / a);
+        SectionProperties {
+            area: a,
+            centroid: [origin[0] + cx, origin[1] + cy],
+            second_moments: [syy - a * cy * cy, sxx - a * cx * cx, sxy - a * cx * cy],
+            min: self.min,
+            max: self.max,
+        }
+    }
+}
+
+/// Section properties of tessellator output.
+#[derive(Clone, Debug)]
+pub struct Section {
+    polygons: Vec<SectionProperties>,
+    /// Island index of each polygon.
+    island_of: Vec<usize>,
+    islands: Vec<SectionProperties>,
+    total: SectionProperties,
+}
+
+/// Union-find root of `i`, halving the path on the way.
+fn root(parent: &mut [usize], mut i: usize) -> usize {
+    while parent[i] != i {
+        parent[i] = parent[parent[i]];
+        i = parent[i];
+    }
+    i
+}
+
+/// Corner lists of the output polygons.
+fn corners(
+    vertices: &[Real],
+    vertex_size: usize,
+    elements: &[u32],
+    element_type: ElementType,
+    poly_size: usize,
+) -> Vec<Vec<[Real; 2]>> {
+    let xy = |v: u32| {
+        let v = v as usize * vertex_size;
+        [vertices[v], vertices[v + 1]]
+    };
+    match element_type {
+        ElementType::Polygons | ElementType::ConnectedPolygons => {
+            let stride = if element_type == ElementType::Polygons { poly_size } else { poly_size * 2 };
+            elements
+                .chunks_exact(stride)
+                .map(|e| e[..poly_size].iter().take_while(|&&v| v != TESS_UNDEF).map(|&v| xy(v)).collect())
+                .collect()
+        }
+        ElementType::ConvexPartition => {
+            elements.chunks_exact(2).map(|e| (e[0]..e[0] + e[1]).map(xy).collect()).collect()
+        }
+        ElementType::BoundaryContours | ElementType::EdgeList => Vec::new(),
+    }
+}
+
+impl Section {
+    /// Build from the output of a `tessellate` call: `vertices()` with
+    /// `vertex_size` components each, and `elements()` as written for
+    /// `element_type` and `poly_size`.  `Polygons`, `ConnectedPolygons`
+    /// and `ConvexPartition` output are read; `BoundaryContours` and
+    /// `EdgeList` give an empty section.
+    pub fn new(
+        vertices: &[Real],
+        vertex_size: usize,
+        elements: &[u32],
+        element_type: ElementType,
+        poly_size: usize,
+    ) -> Self {
+        let polys = corners(vertices, vertex_size, elements, element_type, poly_size);
+        let mut bounds = Integrals::EMPTY;
+        for p in polys.iter().flatten() {
+            bounds.extend(*p);
+        }
+        let origin = if bounds.min[0] > bounds.max[0] {
+            [0.0, 0.0]
+        } else {
+            [0.5 * (bounds.min[0] + bounds.max[0]), 0.5 * (bounds.min[1] + bounds.max[1])]
+        };
+        let integrals: Vec<Integrals> = polys.iter().map(|p| Integrals::polygon(p, origin)).collect();
+
+        // Union the polygons across shared edges, keyed by end point bits so
+        // pieces that repeat their vertices (ConvexPartition) still join.
+        let mut parent: Vec<usize> = (0..polys.len()).collect();
+        let key = |p: [Real; 2]| [(p[0] + 0.0).to_bits(), (p[1] + 0.0).to_bits()];
+        let mut owner: HashMap<([u64; 2], [u64; 2]), usize> = HashMap::new();
+        for (i, p) in polys.iter().enumerate() {
+            for (j, &a) in p.iter().enumerate() {
+                let (a, b) = (key(a), key(p[(j + 1) % p.len()]));
+                if a == b {
+                    continue;
+                }
+                match owner.entry(if a < b { (a, b) } else { (b, a) }) {
+                    Entry::Occupied(o) => {
+                        let (ri, ro) = (root(&mut parent, i), root(&mut parent, *o.get()));
+                        parent[ri.max(ro)] = ri.min(ro);
+                    }
+                    Entry::Vacant(v) => {
+                        v.insert(i);
+                    }
+                }
+            }
+        }
+
+        let mut island_of = vec![usize::MAX; polys.len()];
+        let mut island_sums: Vec<Integrals> = Vec::new();
+        let mut total = Integrals::EMPTY;
+        for i in 0..polys.len() {
+            let r = root(&mut parent, i);
+            if island_of[r] == usize::MAX {
+                island_of[r] = island_sums.len();
+                island_sums.push(Integrals::EMPTY);
+            }
+            island_of[i] = island_of[r];
+            island_sums[island_of[i]].add(&integrals[i]);
+            total.add(&integrals[i]);
+        }
+        Section {
+            polygons: integrals.iter().map(|it| it.properties(origin)).collect(),
+            island_of,
+            islands: island_sums.iter().map(|it| it.properties(origin)).collect(),
+            total: total.properties(origin),
+        }
+    }
+
+    /// Properties of each output polygon, in output order.
+    pub fn polygons(&self) -> &[SectionProperties] {
+        &self.polygons
+    }
+
+    /// Properties of each island, numbered in order of their first polygon.
+    pub fn islands(&self) -> &[SectionProperties] {
+        &self.islands
+    }
+
+    /// The island polygon `poly` belongs to.
+    pub fn island_of(&self, poly: usize) -> usize {
+        self.island_of[poly]
+    }
+
+    /// Properties of the whole fill; all zero when there is none.
+    pub fn total(&self) -> SectionProperties {
+        self.total
+    }
+}
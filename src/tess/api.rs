@@ -7,8 +7,12 @@
 // (`tests/file_compliance.rs`) keeps `tess/mod.rs` under its limit.
 
 use crate::geom::Real;
+use crate::mesh::{Mesh, MeshReport};
 
-use super::{ElementType, TessOption, TessStatus, Tessellator, WindingRule};
+use super::{
+    BrepFace, ContourHandle, ContourNode, ElementType, FaceError, FaceMesh, Orientation, PlaneFit, PolygonWithHoles,
+    TessOption, TessStatus, Tessellator, UvMapping, VoronoiCell, WindingRule,
+};
 
 /// High-level tessellator (public interface).
 pub struct TessellatorApi {
@@ -24,9 +28,53 @@ impl TessellatorApi {
     pub fn set_option(&mut self, option: TessOption, value: bool) {
         self.inner.set_option(option, value);
     }
+    pub fn set_contour_orientation(&mut self, orientation: Option<Orientation>) {
+        self.inner.set_contour_orientation(orientation);
+    }
+    pub fn set_min_angle(&mut self, degrees: Real) {
+        self.inner.set_min_angle(degrees);
+    }
+    pub fn set_max_area(&mut self, area: Real) {
+        self.inner.set_max_area(area);
+    }
+    pub fn set_weld_epsilon(&mut self, epsilon: Real) {
+        self.inner.set_weld_epsilon(epsilon);
+    }
+    pub fn set_snap_grid(&mut self, spacing: Real) {
+        self.inner.set_snap_grid(spacing);
+    }
+    pub fn set_uv_mapping(&mut self, mapping: Option<UvMapping>) {
+        self.inner.set_uv_mapping(mapping);
+    }
+    pub fn set_planarity_tolerance(&mut self, tolerance: Real) {
+        self.inner.set_planarity_tolerance(tolerance);
+    }
+    pub fn fit_plane(&self) -> Option<PlaneFit> {
+        self.inner.fit_plane()
+    }
+    pub fn tessellate_faces(
+        &mut self,
+        positions: &[Real],
+        faces: &[BrepFace],
+        winding_rule: WindingRule,
+    ) -> Result<FaceMesh, FaceError> {
+        self.inner.tessellate_faces(positions, faces, winding_rule)
+    }
     pub fn add_contour(&mut self, size: usize, vertices: &[Real]) {
         self.inner.add_contour(size, vertices);
     }
+    pub fn add_points(&mut self, size: usize, points: &[Real]) {
+        self.inner.add_points(size, points);
+    }
+    pub fn add_constraint(&mut self, size: usize, vertices: &[Real]) {
+        self.inner.add_constraint(size, vertices);
+    }
+    pub fn winding_number(&self, point: [Real; 3], normal: Option<[Real; 3]>) -> i32 {
+        self.inner.winding_number(point, normal)
+    }
+    pub fn winding_numbers(&self, size: usize, points: &[Real], normal: Option<[Real; 3]>) -> Vec<i32> {
+        self.inner.winding_numbers(size, points, normal)
+    }
     pub fn tessellate(
         &mut self,
         winding_rule: WindingRule,
@@ -38,9 +86,54 @@ impl TessellatorApi {
         self.inner
             .tessellate(winding_rule, element_type, poly_size, vertex_size, normal)
     }
+    pub fn triangulate_points(
+        &mut self,
+        size: usize,
+        points: &[Real],
+        vertex_size: usize,
+        normal: Option<[Real; 3]>,
+    ) -> bool {
+        self.inner.triangulate_points(size, points, vertex_size, normal)
+    }
+    pub fn insert_contour(&mut self, size: usize, vertices: &[Real]) -> ContourHandle {
+        self.inner.insert_contour(size, vertices)
+    }
+    pub fn update_contour(&mut self, handle: ContourHandle, size: usize, vertices: &[Real]) -> bool {
+        self.inner.update_contour(handle, size, vertices)
+    }
+    pub fn remove_contour(&mut self, handle: ContourHandle) -> bool {
+        self.inner.remove_contour(handle)
+    }
+    pub fn retessellate(
+        &mut self,
+        winding_rule: WindingRule,
+        element_type: ElementType,
+        poly_size: usize,
+        vertex_size: usize,
+        normal: Option<[Real; 3]>,
+    ) -> bool {
+        self.inner
+            .retessellate(winding_rule, element_type, poly_size, vertex_size, normal)
+    }
+    pub fn resweep_count(&self) -> usize {
+        self.inner.resweep_count()
+    }
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+    pub fn weld_count(&self) -> usize {
+        self.inner.weld_count()
+    }
     pub fn vertex_count(&self) -> usize {
         self.inner.vertex_count()
     }
+    /// The kept half-edge mesh — see [`Tessellator::mesh`].
+    pub fn mesh(&self) -> Option<&Mesh> {
+        self.inner.mesh()
+    }
+    pub fn mesh_report(&self) -> Option<&MeshReport> {
+        self.inner.mesh_report()
+    }
     pub fn element_count(&self) -> usize {
         self.inner.element_count()
     }
@@ -57,6 +150,38 @@ impl TessellatorApi {
     pub fn edge_flags(&self) -> &[u8] {
         self.inner.edge_flags()
     }
+    pub fn uvs(&self) -> &[Real] {
+        self.inner.uvs()
+    }
+    pub fn normal(&self) -> [Real; 3] {
+        self.inner.normal()
+    }
+    pub fn projection_basis(&self) -> ([Real; 3], [Real; 3]) {
+        self.inner.projection_basis()
+    }
+    pub fn projected_bounds(&self) -> ([Real; 2], [Real; 2]) {
+        self.inner.projected_bounds()
+    }
+    pub fn projected_vertices(&self) -> Vec<Real> {
+        self.inner.projected_vertices()
+    }
+    /// Ring nesting of `BoundaryContours` output — see [`Tessellator::contour_tree`].
+    pub fn contour_tree(&self) -> &[ContourNode] {
+        self.inner.contour_tree()
+    }
+    pub fn polygons_with_holes(&self) -> Vec<PolygonWithHoles> {
+        self.inner.polygons_with_holes()
+    }
+    /// Voronoi cells — see [`Tessellator::voronoi_cells`].
+    pub fn voronoi_cells(&self) -> &[VoronoiCell] {
+        self.inner.voronoi_cells()
+    }
+    pub fn voronoi_vertices(&self) -> &[Real] {
+        self.inner.voronoi_vertices()
+    }
+    pub fn voronoi_indices(&self) -> &[u32] {
+        self.inner.voronoi_indices()
+    }
     pub fn status(&self) -> TessStatus {
         self.inner.get_status()
     }
@@ -399,7 +399,7 @@ impl Tessellator {
         let euo_s = self.mesh.as_ref().unwrap().verts[e_up_org as usize].s;
         let euo_t = self.mesh.as_ref().unwrap().verts[e_up_org as usize].t;
 
-        if crate::geom::edge_sign(eud_s, eud_t, self.event_s, self.event_t, euo_s, euo_t) == 0.0 {
+        if crate::geom::edge_sign_fn(self.exact_predicates)(eud_s, eud_t, self.event_s, self.event_t, euo_s, euo_t) == 0.0 {
             self.connect_left_degenerate(reg_up, v_event);
             return;
         }
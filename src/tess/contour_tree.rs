@@ -0,0 +1,218 @@
+// Copyright 2025 Lars Brubaker
+// License: SGI Free Software License B (MIT-compatible)
+//
+//! Polygon-with-holes structure for `ElementType::BoundaryContours`.
+//!
+//! The sweep leaves every boundary ring with the inside region on its left
+//! in the projected (s, t) plane, so a ring's signed area says whether it is
+//! an outer boundary (positive) or a hole (negative).  Nesting is recovered
+//! by testing one edge midpoint of each ring against the other rings: rings
+//! produced by the sweep never cross, so the smallest ring containing that
+//! point is the parent.
+
+use crate::geom::Real;
+
+use super::geometry::{cross, dot};
+use super::Tessellator;
+
+/// Winding direction of a ring, seen from the tip of the effective normal
+/// (for 2D input: the usual counter-clockwise in a Y-up X/Y plane).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Orientation {
+    CounterClockwise,
+    Clockwise,
+}
+
+impl Orientation {
+    pub fn reversed(self) -> Orientation {
+        match self {
+            Orientation::CounterClockwise => Orientation::Clockwise,
+            Orientation::Clockwise => Orientation::CounterClockwise,
+        }
+    }
+}
+
+/// One boundary ring of a `BoundaryContours` tessellation.  Node `i`
+/// describes contour `i` (the `[start, count]` pair at `elements()[2 * i]`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContourNode {
+    /// Smallest ring enclosing this one, or `None` for a top-level ring.
+    pub parent: Option<usize>,
+    /// Rings directly enclosed by this one.
+    pub children: Vec<usize>,
+    /// Nesting depth: 0 for top-level outer rings, 1 for their holes, 2 for
+    /// islands inside those holes, and so on.
+    pub depth: u32,
+    /// `true` when the filled region lies outside this ring.
+    pub is_hole: bool,
+    /// Direction of the ring's vertex order in the output.  Follows
+    /// `set_contour_orientation` when one was requested.
+    pub orientation: Orientation,
+    /// Unsigned area enclosed by the ring, measured in the projection plane.
+    pub area: Real,
+}
+
+/// An outer ring together with the holes cut directly out of it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PolygonWithHoles {
+    pub outer: usize,
+    pub holes: Vec<usize>,
+}
+
+fn signed_area(ring: &[[Real; 2]]) -> Real {
+    let mut area = 0.0;
+    for (i, p) in ring.iter().enumerate() {
+        let q = ring[(i + 1) % ring.len()];
+        area += p[0] * q[1] - q[0] * p[1];
+    }
+    area * 0.5
+}
+
+/// Even-odd crossing test of `p` against a closed ring.
+fn ring_contains(ring: &[[Real; 2]], p: [Real; 2]) -> bool {
+    let mut inside = false;
+    let mut j = ring.len() - 1;
+    for i in 0..ring.len() {
+        let (a, b) = (ring[i], ring[j]);
+        if (a[1] > p[1]) != (b[1] > p[1]) {
+            let s = a[0] + (p[1] - a[1]) / (b[1] - a[1]) * (b[0] - a[0]);
+            if p[0] < s {
+                inside = !inside;
+            }
+        }
+        j = i;
+    }
+    inside
+}
+
+impl Tessellator {
+    /// `+1.0` when counter-clockwise in (s, t) is counter-clockwise around
+    /// the effective normal, `-1.0` when the projection mirrors it.
+    pub(crate) fn projection_handedness(&self) -> Real {
+        if dot(&cross(&self.s_unit, &self.t_unit), &self.normal) < 0.0 {
+            -1.0
+        } else {
+            1.0
+        }
+    }
+
+    /// Build `out_contour_tree` from the contours just written by
+    /// `output_contours`, from the (s, t) in `out_projected`.
+    pub(crate) fn build_contour_tree(&mut self, vertex_size: usize) {
+        let count = self.out_element_count;
+        let handedness = self.projection_handedness();
+        let mut nodes = std::mem::take(&mut self.out_contour_tree);
+        nodes.clear();
+        for i in 0..count {
+            let area = signed_area(self.ring_projected(i));
+            let ccw = area * handedness >= 0.0;
+            nodes.push(ContourNode {
+                parent: None,
+                children: self.spare_children.pop().unwrap_or_default(),
+                depth: 0,
+                is_hole: area < 0.0,
+                orientation: if ccw {
+                    Orientation::CounterClockwise
+                } else {
+                    Orientation::Clockwise
+                },
+                area: area.abs(),
+            });
+        }
+
+        for i in 0..count {
+            let ring = self.ring_projected(i);
+            let probe = [
+                0.5 * (ring[0][0] + ring[1 % ring.len()][0]),
+                0.5 * (ring[0][1] + ring[1 % ring.len()][1]),
+            ];
+            let mut best: Option<usize> = None;
+            for j in 0..count {
+                if j == i || nodes[j].area <= nodes[i].area || !ring_contains(self.ring_projected(j), probe) {
+                    continue;
+                }
+                if best.map_or(true, |b| nodes[j].area < nodes[b].area) {
+                    best = Some(j);
+                }
+            }
+            nodes[i].parent = best;
+        }
+
+        if let Some(outer) = self.contour_orientation {
+            for (i, node) in nodes.iter_mut().enumerate() {
+                let wanted = if node.is_hole { outer.reversed() } else { outer };
+                if node.orientation != wanted {
+                    self.reverse_contour(i, vertex_size);
+                    node.orientation = wanted;
+                }
+            }
+        }
+        for i in 0..count {
+            if let Some(p) = nodes[i].parent {
+                nodes[p].children.push(i);
+            }
+            let mut depth = 0;
+            let mut up = nodes[i].parent;
+            while let Some(p) = up {
+                depth += 1;
+                up = nodes[p].parent;
+            }
+            nodes[i].depth = depth;
+        }
+        self.out_contour_tree = nodes;
+    }
+
+    /// The (s, t) of output contour `i`.
+    fn ring_projected(&self, i: usize) -> &[[Real; 2]] {
+        let start = self.out_elements[i * 2] as usize;
+        let len = self.out_elements[i * 2 + 1] as usize;
+        &self.out_projected[start..start + len]
+    }
+
+    /// Empty `out_contour_tree`, keeping the nodes' child lists for the
+    /// next tree.
+    pub(crate) fn clear_contour_tree(&mut self) {
+        for mut node in self.out_contour_tree.drain(..) {
+            node.children.clear();
+            self.spare_children.push(node.children);
+        }
+    }
+
+    /// Reverse the vertex order of output contour `i` in place.
+    fn reverse_contour(&mut self, i: usize, vertex_size: usize) {
+        let start = self.out_elements[i * 2] as usize;
+        let len = self.out_elements[i * 2 + 1] as usize;
+        self.out_vertex_indices[start..start + len].reverse();
+        self.out_projected[start..start + len].reverse();
+        let coords = &mut self.out_vertices[start * vertex_size..(start + len) * vertex_size];
+        coords.reverse();
+        for vertex in coords.chunks_mut(vertex_size) {
+            vertex.reverse();
+        }
+    }
+
+    /// Nesting of the `BoundaryContours` output: one node per contour, in
+    /// `elements()` order.  Empty for every other element type.
+    pub fn contour_tree(&self) -> &[ContourNode] {
+        &self.out_contour_tree
+    }
+
+    /// Group the `BoundaryContours` output into outer rings and their holes.
+    /// Islands inside a hole start a polygon of their own.
+    pub fn polygons_with_holes(&self) -> Vec<PolygonWithHoles> {
+        self.out_contour_tree
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| !node.is_hole)
+            .map(|(i, node)| PolygonWithHoles {
+                outer: i,
+                holes: node
+                    .children
+                    .iter()
+                    .copied()
+                    .filter(|&c| self.out_contour_tree[c].is_hole)
+                    .collect(),
+            })
+            .collect()
+    }
+}
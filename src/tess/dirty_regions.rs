@@ -8,7 +8,7 @@
 //! left/right splices and edge intersections, splitting and splicing the
 //! mesh until the sweep invariants hold again.
 
-use crate::geom::{edge_intersect, edge_sign, vert_eq, vert_leq};
+use crate::geom::{edge_intersect, edge_sign_fn, vert_eq, vert_leq};
 use crate::mesh::{INVALID};
 use crate::priorityq::INVALID_HANDLE;
 use super::geometry::{compute_intersect_coords};
@@ -16,6 +16,7 @@ use super::{Tessellator, TESS_UNDEF, RegionIdx};
 
 impl Tessellator {
     pub(super) fn check_for_right_splice(&mut self, reg_up: RegionIdx) -> bool {
+        let edge_sign = edge_sign_fn(self.exact_predicates);
         let reg_lo = self.region_below(reg_up);
         if reg_lo == INVALID {
             return false;
@@ -97,6 +98,7 @@ impl Tessellator {
     }
 
     pub(super) fn check_for_left_splice(&mut self, reg_up: RegionIdx) -> bool {
+        let edge_sign = edge_sign_fn(self.exact_predicates);
         let reg_lo = self.region_below(reg_up);
         if reg_lo == INVALID {
             return false;
@@ -184,6 +186,7 @@ impl Tessellator {
     }
 
     pub(super) fn check_for_intersect(&mut self, reg_up: RegionIdx) -> bool {
+        let edge_sign = edge_sign_fn(self.exact_predicates);
         let reg_lo = self.region_below(reg_up);
         if reg_lo == INVALID {
             return false;
@@ -244,6 +247,8 @@ impl Tessellator {
 
         // Compute intersection
         let (isect_s, isect_t) = edge_intersect(du_s, du_t, ou_s, ou_t, dl_s, dl_t, ol_s, ol_t);
+        let targets = [(ev_s, ev_t), (ou_s, ou_t), (ol_s, ol_t)];
+        let (isect_s, isect_t) = self.snap_intersection(isect_s, isect_t, targets);
 
         // Clamp intersection to sweep event position
         let (isect_s, isect_t) = if vert_leq(isect_s, isect_t, ev_s, ev_t) {
@@ -263,6 +268,7 @@ impl Tessellator {
         } else {
             (isect_s, isect_t)
         };
+        let (isect_s, isect_t) = self.weld_intersection(isect_s, isect_t, targets);
 
         // Check if intersection is at one of the endpoints
         if vert_eq(isect_s, isect_t, ou_s, ou_t) || vert_eq(isect_s, isect_t, ol_s, ol_t) {
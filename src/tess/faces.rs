@@ -0,0 +1,274 @@
+// Copyright 2025 Lars Brubaker
+// License: SGI Free Software License B (MIT-compatible)
+//
+//! Batch tessellation of planar B-rep faces (`Tessellator::tessellate_faces`).
+//!
+//! A face is a set of loops over shared vertex ids: an outer boundary and
+//! its holes, in whatever order the modeller keeps them.  Each face is
+//! tessellated on its own, with its own normal, and the triangles are
+//! gathered into one indexed mesh.  Input vertices keep their id as their
+//! index in that mesh, so faces that share an edge share its vertices and
+//! the result stays connected.  Vertices a face creates (intersections,
+//! Steiner points from refinement) are appended after the input ones.
+//! Refinement may split a loop edge that another face shares; the points
+//! it adds there are handed to every face using the edge and the batch is
+//! run again, until no face adds any, so both sides of a shared edge end
+//! up with the same vertices and the mesh stays watertight.  Points inside
+//! a face belong to that face alone.
+//!
+//! Triangles wind counter-clockwise around their face's normal, which by
+//! default is the Newell normal of its loops (see
+//! [`Tessellator::fit_plane`]), so consistently oriented faces give a
+//! consistently oriented mesh.  The tessellator's options and settings
+//! (CDT, refinement, orthonormal projection, planarity tolerance) apply to
+//! every face.
+
+use std::collections::HashMap;
+
+use crate::geom::Real;
+
+use super::geometry::dot;
+use super::{ElementType, TessStatus, Tessellator, WindingRule, TESS_UNDEF};
+
+/// One planar face of a B-rep model.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BrepFace {
+    /// Vertex ids of each loop, without repeating the first at the end.
+    pub loops: Vec<Vec<u32>>,
+    /// The face's normal, or `None` to take the Newell normal of its loops.
+    pub normal: Option<[Real; 3]>,
+}
+
+/// The combined triangle mesh of a batch of faces.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FaceMesh {
+    /// x, y and z of each vertex: the input positions, indexed by vertex
+    /// id, followed by the vertices the faces created.
+    pub vertices: Vec<Real>,
+    /// Three vertex indices per triangle.
+    pub triangles: Vec<u32>,
+    /// The face each triangle came from.
+    pub triangle_faces: Vec<u32>,
+}
+
+/// A face that could not be tessellated.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FaceError {
+    pub face: usize,
+    pub status: TessStatus,
+}
+
+/// Runs of `tessellate_faces` before giving up on the faces agreeing on
+/// the points along their shared edges.
+const MAX_SPLIT_ROUNDS: usize = 16;
+
+/// Points added on loop edges by refinement, shared by every face that
+/// uses the edge.
+#[derive(Default)]
+struct EdgeSplits {
+    /// Per edge (lower id, higher id): the position along the edge from
+    /// the lower id and the id of every point on it, in that order.
+    points: HashMap<(u32, u32), Vec<(Real, u32)>>,
+    /// x, y and z of each point, id `input_count + i`.
+    positions: Vec<Real>,
+}
+
+impl EdgeSplits {
+    fn position<'a>(&'a self, positions: &'a [Real], id: u32) -> &'a [Real] {
+        let input_count = positions.len() / 3;
+        let (coords, i) = match (id as usize).checked_sub(input_count) {
+            Some(i) => (&self.positions[..], i),
+            None => (positions, id as usize),
+        };
+        &coords[i * 3..i * 3 + 3]
+    }
+
+    /// The loop `ids` with the points of each edge inserted in order, and
+    /// the edge each consecutive pair of the result lies on.
+    fn expand(&self, ids: &[u32], out: &mut Vec<u32>, edges: &mut Vec<(u32, u32)>) {
+        out.clear();
+        edges.clear();
+        for (i, &a) in ids.iter().enumerate() {
+            let b = ids[(i + 1) % ids.len()];
+            let key = (a.min(b), a.max(b));
+            out.push(a);
+            edges.push(key);
+            if let Some(points) = self.points.get(&key) {
+                let mut add = |&(_, id): &(Real, u32)| {
+                    out.push(id);
+                    edges.push(key);
+                };
+                if a < b {
+                    points.iter().for_each(&mut add);
+                } else {
+                    points.iter().rev().for_each(&mut add);
+                }
+            }
+        }
+    }
+
+    /// Share the new output vertices the last run placed on the loop edge
+    /// `key` between `a` and `b`.  Returns whether any were added.
+    fn add_chain(&mut self, positions: &[Real], key: (u32, u32), chain: &[[Real; 3]]) -> bool {
+        let pa: [Real; 3] = self.position(positions, key.0).try_into().unwrap();
+        let pb: [Real; 3] = self.position(positions, key.1).try_into().unwrap();
+        let d = [pb[0] - pa[0], pb[1] - pa[1], pb[2] - pa[2]];
+        let len2 = dot(&d, &d);
+        let mut added = false;
+        for p in chain {
+            let u = [p[0] - pa[0], p[1] - pa[1], p[2] - pa[2]];
+            let t = dot(&u, &d) / len2;
+            let off = [u[0] - t * d[0], u[1] - t * d[1], u[2] - t * d[2]];
+            // Only points on the edge itself, not crossings with other loops.
+            if !(t > 0.0 && t < 1.0) || dot(&off, &off) > 1e-18 * len2 {
+                continue;
+            }
+            let id = (positions.len() / 3 + self.positions.len() / 3) as u32;
+            self.positions.extend_from_slice(p);
+            let points = self.points.entry(key).or_default();
+            let at = points.partition_point(|q| q.0 < t);
+            points.insert(at, (t, id));
+            added = true;
+        }
+        added
+    }
+}
+
+impl Tessellator {
+    /// Tessellate every face of `faces` over `positions` (x, y, z per
+    /// vertex id) with `winding_rule` and merge the triangles into one
+    /// mesh (see the module docs).  Pending input is discarded first.  A
+    /// loop naming a vertex id without a position, or a face failing to
+    /// tessellate, stops the batch with that face's index and status; so
+    /// does a face still splitting shared edges after 16 runs
+    /// (`InvalidInput`).
+    pub fn tessellate_faces(
+        &mut self,
+        positions: &[Real],
+        faces: &[BrepFace],
+        winding_rule: WindingRule,
+    ) -> Result<FaceMesh, FaceError> {
+        let input_count = positions.len() / 3;
+        let positions = &positions[..input_count * 3];
+        let mut splits = EdgeSplits::default();
+        let mut split = None;
+        for _ in 0..MAX_SPLIT_ROUNDS {
+            let mut mesh = FaceMesh { vertices: positions.to_vec(), ..FaceMesh::default() };
+            mesh.vertices.extend_from_slice(&splits.positions);
+            split = None;
+            for (f, face) in faces.iter().enumerate() {
+                if self.tessellate_face(positions, &mut splits, face, winding_rule, &mut mesh, f)? {
+                    split = Some(f);
+                }
+            }
+            self.reset();
+            if split.is_none() {
+                return Ok(mesh);
+            }
+        }
+        self.status = TessStatus::InvalidInput;
+        Err(FaceError { face: split.unwrap_or(0), status: self.status })
+    }
+
+    /// Tessellate face `f` with the points in `splits` on its edges and
+    /// append its triangles to `mesh`.  Returns whether the run put new
+    /// points on its edges, which are then added to `splits`.
+    fn tessellate_face(
+        &mut self,
+        positions: &[Real],
+        splits: &mut EdgeSplits,
+        face: &BrepFace,
+        winding_rule: WindingRule,
+        mesh: &mut FaceMesh,
+        f: usize,
+    ) -> Result<bool, FaceError> {
+        let input_count = positions.len() / 3;
+        self.reset();
+        let (mut ids, mut coords) = (Vec::new(), Vec::new());
+        let mut loops = Vec::with_capacity(face.loops.len());
+        for loop_ids in &face.loops {
+            if loop_ids.iter().any(|&id| id as usize >= input_count) {
+                self.status = TessStatus::InvalidInput;
+                return Err(FaceError { face: f, status: self.status });
+            }
+            let mut edges = Vec::new();
+            splits.expand(loop_ids, &mut ids, &mut edges);
+            coords.clear();
+            coords.extend(ids.iter().flat_map(|&id| splits.position(positions, id)));
+            self.add_contour_indexed(3, &coords, Some(&ids));
+            loops.push((ids.clone(), edges));
+        }
+        let normal = face.normal.or_else(|| self.fit_plane().map(|fit| fit.normal));
+        if !self.tessellate(winding_rule, ElementType::Polygons, 3, 3, normal) {
+            return Err(FaceError { face: f, status: self.status });
+        }
+
+        // Input and shared vertices go back to their id; new ones are
+        // appended.
+        let index: Vec<u32> = self
+            .out_vertex_indices
+            .iter()
+            .zip(self.out_vertices.chunks_exact(3))
+            .map(|(&id, p)| {
+                if id != TESS_UNDEF {
+                    return id;
+                }
+                mesh.vertices.extend_from_slice(p);
+                (mesh.vertices.len() / 3 - 1) as u32
+            })
+            .collect();
+        mesh.triangles.extend(self.out_elements.iter().map(|&v| index[v as usize]));
+        mesh.triangle_faces.resize(mesh.triangle_faces.len() + self.out_element_count, f as u32);
+
+        // Follow the boundary edges from each loop vertex to collect the
+        // new vertices refinement put between it and the next one.  A vertex
+        // where two loops touch has a boundary edge leaving along each.
+        let mut next: HashMap<u32, Vec<u32>> = HashMap::new();
+        for (tri, flags) in self.out_elements.chunks_exact(3).zip(self.out_edge_flags.chunks_exact(3)) {
+            for i in 0..3 {
+                if flags[i] == 1 {
+                    next.entry(tri[i]).or_default().push(tri[(i + 1) % 3]);
+                }
+            }
+        }
+        let out_of: HashMap<u32, u32> = self
+            .out_vertex_indices
+            .iter()
+            .enumerate()
+            .filter(|(_, &id)| id != TESS_UNDEF)
+            .map(|(v, &id)| (id, v as u32))
+            .collect();
+        let walk = |mut v: u32, to: u32| {
+            let mut chain = Vec::new();
+            for _ in 0..self.out_vertex_count {
+                let id = self.out_vertex_indices[v as usize];
+                if id != TESS_UNDEF {
+                    return (id == to).then_some(chain);
+                }
+                let p = &self.out_vertices[v as usize * 3..v as usize * 3 + 3];
+                chain.push([p[0], p[1], p[2]]);
+                v = *next.get(&v)?.first()?;
+            }
+            None
+        };
+        let chain = |from: u32, to: u32| {
+            let starts = next.get(out_of.get(&from)?)?;
+            starts.iter().find_map(|&v| walk(v, to))
+        };
+        let mut split = false;
+        for (ids, edges) in &loops {
+            for (i, &a) in ids.iter().enumerate() {
+                let b = ids[(i + 1) % ids.len()];
+                let found = chain(a, b).or_else(|| {
+                    let mut back = chain(b, a)?;
+                    back.reverse();
+                    Some(back)
+                });
+                if let Some(points) = found.filter(|c| !c.is_empty()) {
+                    split |= splits.add_chain(positions, edges[i], &points);
+                }
+            }
+        }
+        Ok(split)
+    }
+}
@@ -12,6 +12,14 @@ pub(crate) fn dot(u: &[Real; 3], v: &[Real; 3]) -> Real {
     u[0] * v[0] + u[1] * v[1] + u[2] * v[2]
 }
 
+pub(crate) fn cross(u: &[Real; 3], v: &[Real; 3]) -> [Real; 3] {
+    [
+        u[1] * v[2] - u[2] * v[1],
+        u[2] * v[0] - u[0] * v[2],
+        u[0] * v[1] - u[1] * v[0],
+    ]
+}
+
 pub(crate) fn long_axis(v: &[Real; 3]) -> usize {
     let mut i = 0;
     if v[1].abs() > v[0].abs() {
@@ -23,6 +31,36 @@ pub(crate) fn long_axis(v: &[Real; 3]) -> usize {
     i
 }
 
+/// `v` scaled to unit length; zero stays zero.
+fn normalized(v: [Real; 3]) -> [Real; 3] {
+    let len = dot(&v, &v).sqrt();
+    if len > 0.0 {
+        [v[0] / len, v[1] / len, v[2] / len]
+    } else {
+        v
+    }
+}
+
+/// The (s, t) axes to project onto for normal `norm`: the two coordinate
+/// axes after `long_axis(norm)`, t negated when `norm` points backwards
+/// along it.  With `orthonormal` (libtess2's `TRUE_PROJECT`), the first
+/// one is made exactly perpendicular to `norm` instead and t completes a
+/// right-handed frame, so distances and angles in the plane are kept.
+pub(crate) fn projection_axes(norm: &[Real; 3], orthonormal: bool) -> ([Real; 3], [Real; 3]) {
+    let i = long_axis(norm);
+    let mut su = [0.0; 3];
+    su[(i + 1) % 3] = 1.0;
+    let n = normalized(*norm);
+    if orthonormal && n != [0.0; 3] {
+        let w = dot(&su, &n);
+        let su = normalized([su[0] - w * n[0], su[1] - w * n[1], su[2] - w * n[2]]);
+        return (su, normalized(cross(&n, &su)));
+    }
+    let mut tu = [0.0; 3];
+    tu[(i + 2) % 3] = if norm[i] > 0.0 { 1.0 } else { -1.0 };
+    (su, tu)
+}
+
 pub(crate) fn short_axis(v: &[Real; 3]) -> usize {
     let mut i = 0;
     if v[1].abs() < v[0].abs() {
@@ -35,41 +73,44 @@ pub(crate) fn short_axis(v: &[Real; 3]) -> usize {
 }
 
 pub(crate) fn compute_normal(mesh: &Mesh, norm: &mut [Real; 3]) {
-    let first_v = mesh.verts[V_HEAD as usize].next;
-    if first_v == V_HEAD {
+    let mut v = V_HEAD;
+    let coords = std::iter::from_fn(move || {
+        v = mesh.verts[v as usize].next;
+        (v != V_HEAD).then(|| mesh.verts[v as usize].coords)
+    });
+    compute_normal_of(coords, norm);
+}
+
+/// `compute_normal` for any set of points: the normal of the largest
+/// triangle spanned by the two extremes of the longest bounding-box axis
+/// and a third point.
+pub(crate) fn compute_normal_of<I>(points: I, norm: &mut [Real; 3])
+where
+    I: Iterator<Item = [Real; 3]> + Clone,
+{
+    let Some(first) = points.clone().next() else {
         norm[0] = 0.0;
         norm[1] = 0.0;
         norm[2] = 1.0;
         return;
-    }
+    };
 
-    let mut max_val = [0.0 as Real; 3];
-    let mut min_val = [0.0 as Real; 3];
-    let mut max_vert = [V_HEAD; 3];
-    let mut min_vert = [V_HEAD; 3];
+    let mut max_val = first;
+    let mut min_val = first;
+    let mut max_vert = [first; 3];
+    let mut min_vert = [first; 3];
 
-    for i in 0..3 {
-        let c = mesh.verts[first_v as usize].coords[i];
-        min_val[i] = c;
-        min_vert[i] = first_v;
-        max_val[i] = c;
-        max_vert[i] = first_v;
-    }
-
-    let mut v = mesh.verts[V_HEAD as usize].next;
-    while v != V_HEAD {
+    for c in points.clone() {
         for i in 0..3 {
-            let c = mesh.verts[v as usize].coords[i];
-            if c < min_val[i] {
-                min_val[i] = c;
-                min_vert[i] = v;
+            if c[i] < min_val[i] {
+                min_val[i] = c[i];
+                min_vert[i] = c;
             }
-            if c > max_val[i] {
-                max_val[i] = c;
-                max_vert[i] = v;
+            if c[i] > max_val[i] {
+                max_val[i] = c[i];
+                max_vert[i] = c;
             }
         }
-        v = mesh.verts[v as usize].next;
     }
 
     let mut i = 0;
@@ -88,20 +129,11 @@ pub(crate) fn compute_normal(mesh: &Mesh, norm: &mut [Real; 3]) {
 
     let v1 = min_vert[i];
     let v2 = max_vert[i];
-    let d1 = [
-        mesh.verts[v1 as usize].coords[0] - mesh.verts[v2 as usize].coords[0],
-        mesh.verts[v1 as usize].coords[1] - mesh.verts[v2 as usize].coords[1],
-        mesh.verts[v1 as usize].coords[2] - mesh.verts[v2 as usize].coords[2],
-    ];
+    let d1 = [v1[0] - v2[0], v1[1] - v2[1], v1[2] - v2[2]];
 
     let mut max_len2 = 0.0 as Real;
-    let mut v = mesh.verts[V_HEAD as usize].next;
-    while v != V_HEAD {
-        let d2 = [
-            mesh.verts[v as usize].coords[0] - mesh.verts[v2 as usize].coords[0],
-            mesh.verts[v as usize].coords[1] - mesh.verts[v2 as usize].coords[1],
-            mesh.verts[v as usize].coords[2] - mesh.verts[v2 as usize].coords[2],
-        ];
+    for c in points {
+        let d2 = [c[0] - v2[0], c[1] - v2[1], c[2] - v2[2]];
         let tn = [
             d1[1] * d2[2] - d1[2] * d2[1],
             d1[2] * d2[0] - d1[0] * d2[2],
@@ -112,7 +144,6 @@ pub(crate) fn compute_normal(mesh: &Mesh, norm: &mut [Real; 3]) {
             max_len2 = tl2;
             *norm = tn;
         }
-        v = mesh.verts[v as usize].next;
     }
 
     if max_len2 <= 0.0 {
@@ -123,7 +154,9 @@ pub(crate) fn compute_normal(mesh: &Mesh, norm: &mut [Real; 3]) {
     }
 }
 
-pub(crate) fn check_orientation(mesh: &mut Mesh) {
+/// Flips the `t` coordinate of every vertex when the positively wound
+/// contours have negative area.  Returns `true` if it flipped.
+pub(crate) fn check_orientation(mesh: &mut Mesh) -> bool {
     let mut area = 0.0 as Real;
     let mut f = mesh.faces[crate::mesh::F_HEAD as usize].next;
     while f != crate::mesh::F_HEAD {
@@ -149,7 +182,38 @@ pub(crate) fn check_orientation(mesh: &mut Mesh) {
             mesh.verts[v as usize].t = -mesh.verts[v as usize].t;
             v = mesh.verts[v as usize].next;
         }
+        return true;
+    }
+    false
+}
+
+/// The area sum `check_orientation` tests, for vertices projected onto
+/// `su` / `tu` instead of their current (s, t).  Negative when
+/// `check_orientation` would mirror t.
+pub(crate) fn check_orientation_sign(mesh: &Mesh, su: &[Real; 3], tu: &[Real; 3]) -> Real {
+    let st = |v: u32| {
+        let c = &mesh.verts[v as usize].coords;
+        (dot(c, su), dot(c, tu))
+    };
+    let mut area = 0.0 as Real;
+    let mut f = mesh.faces[crate::mesh::F_HEAD as usize].next;
+    while f != crate::mesh::F_HEAD {
+        let an = mesh.faces[f as usize].an_edge;
+        if an != INVALID && mesh.edges[an as usize].winding > 0 {
+            let mut e = an;
+            loop {
+                let (os, ot) = st(mesh.edges[e as usize].org);
+                let (ds, dt) = st(mesh.dst(e));
+                area += (os - ds) * (ot + dt);
+                e = mesh.edges[e as usize].lnext;
+                if e == an {
+                    break;
+                }
+            }
+        }
+        f = mesh.faces[f as usize].next;
     }
+    area
 }
 
 /// Mirrors C `GetIntersectData` / `VertexWeights`.
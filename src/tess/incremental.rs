@@ -0,0 +1,515 @@
+// Copyright 2025 Lars Brubaker
+// License: SGI Free Software License B (MIT-compatible)
+//
+//! Incremental re-tessellation of kept contours
+//! (`Tessellator::insert_contour` / `update_contour` / `remove_contour` /
+//! `retessellate`).
+//!
+//! Contours whose projected bounding boxes do not touch cannot interact:
+//! they share no intersections, and a contour adds no winding outside its
+//! box.  Kept contours are therefore grouped into clusters of (transitively)
+//! overlapping boxes, and each cluster's output is cached.  `retessellate`
+//! re-sweeps only the clusters whose membership or contours changed and
+//! splices the cached results of the rest back in.  The inside faces of a
+//! cluster only depend on its own edges, so the result is the same set of
+//! triangles (or polygons) as a full run over all kept contours in handle
+//! order, but it is not the same output: vertices and elements come in a
+//! different order (clusters are emitted one after the other), and no
+//! merged mesh is kept for `TessOption::KeepMesh`.  The savings depend on
+//! the input falling apart into clusters; contours that overlap each other
+//! heavily land in a single cluster, and every edit then re-sweeps all of
+//! them.
+//!
+//! Steiner points, constraints and Voronoi output are not part of
+//! incremental runs: `retessellate` fails with `TessStatus::InvalidInput`
+//! while points or constraints are pending or `TessOption::Voronoi` is set.
+
+use crate::geom::Real;
+
+use super::contour_tree::{ContourNode, Orientation};
+use super::geometry::{compute_normal_of, dot, long_axis, projection_axes};
+use super::planarity::fit_plane;
+use super::{ElementType, TessStatus, Tessellator, WindingRule, TESS_UNDEF};
+
+/// A contour kept by the tessellator between `retessellate` calls.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ContourHandle(u32);
+
+/// Everything a cached cluster result depends on besides its contours.
+#[derive(Clone, PartialEq)]
+struct RunKey {
+    winding_rule: WindingRule,
+    element_type: ElementType,
+    poly_size: usize,
+    vertex_size: usize,
+    normal: [Real; 3],
+    flags: [bool; 4],
+    contour_orientation: Option<Orientation>,
+    settings: [Real; 4],
+}
+
+/// The output of one cluster, with vertex indices numbered within the
+/// cluster (its members' vertices in handle order).
+#[derive(Default)]
+struct Cluster {
+    members: Vec<u32>,
+    vertices: Vec<Real>,
+    projected: Vec<[Real; 2]>,
+    vertex_indices: Vec<u32>,
+    elements: Vec<u32>,
+    edge_flags: Vec<u8>,
+    contour_tree: Vec<ContourNode>,
+    vertex_count: usize,
+    element_count: usize,
+}
+
+#[derive(Default)]
+pub(super) struct Incremental {
+    contours: Vec<Option<Vec<[Real; 3]>>>,
+    dirty: Vec<bool>,
+    clusters: Vec<Cluster>,
+    key: Option<RunKey>,
+    resweeps: usize,
+}
+
+fn read_contour(size: usize, vertices: &[Real]) -> Vec<[Real; 3]> {
+    let size = size.clamp(2, 3);
+    vertices
+        .chunks_exact(size)
+        .map(|p| [p[0], p[1], if size > 2 { p[2] } else { 0.0 }])
+        .collect()
+}
+
+fn overlaps(a: &[Real; 4], b: &[Real; 4]) -> bool {
+    a[0] <= b[2] && b[0] <= a[2] && a[1] <= b[3] && b[1] <= a[3]
+}
+
+/// Group boxes into clusters of transitively overlapping boxes whose
+/// bounding boxes are pairwise disjoint.  Returns sorted member lists.
+fn clusters_of(boxes: &[(u32, [Real; 4])]) -> Vec<Vec<u32>> {
+    let mut groups: Vec<(Vec<u32>, [Real; 4])> = boxes.iter().map(|&(h, b)| (vec![h], b)).collect();
+    loop {
+        groups.sort_by(|a, b| a.1[0].total_cmp(&b.1[0]));
+        let mut merged: Vec<(Vec<u32>, [Real; 4])> = Vec::with_capacity(groups.len());
+        let mut changed = false;
+        // Largest max-s among the merged groups: a box starting past it
+        // overlaps none of them.
+        let mut reach = Real::NEG_INFINITY;
+        for (members, b) in groups {
+            let hit = if b[0] > reach {
+                None
+            } else {
+                merged.iter_mut().rev().find(|g| overlaps(&g.1, &b))
+            };
+            match hit {
+                Some(g) => {
+                    g.0.extend(members);
+                    g.1 = [
+                        g.1[0].min(b[0]),
+                        g.1[1].min(b[1]),
+                        g.1[2].max(b[2]),
+                        g.1[3].max(b[3]),
+                    ];
+                    changed = true;
+                }
+                None => merged.push((members, b)),
+            }
+            reach = reach.max(b[2]);
+        }
+        groups = merged;
+        if !changed {
+            break;
+        }
+    }
+    let mut out: Vec<Vec<u32>> = groups
+        .into_iter()
+        .map(|(mut members, _)| {
+            members.sort_unstable();
+            members
+        })
+        .collect();
+    out.sort_unstable_by_key(|m| m[0]);
+    out
+}
+
+impl Tessellator {
+    /// Keep a contour for incremental runs and return its handle.  `size`
+    /// = 2 or 3 (coords per vertex); `vertices` is flat.
+    pub fn insert_contour(&mut self, size: usize, vertices: &[Real]) -> ContourHandle {
+        let inc = &mut self.incremental;
+        inc.contours.push(Some(read_contour(size, vertices)));
+        inc.dirty.push(true);
+        ContourHandle((inc.contours.len() - 1) as u32)
+    }
+
+    /// Replace the vertices of a kept contour (move it or edit it).
+    /// Returns `false` for a removed handle.
+    pub fn update_contour(
+        &mut self,
+        handle: ContourHandle,
+        size: usize,
+        vertices: &[Real],
+    ) -> bool {
+        let inc = &mut self.incremental;
+        match inc.contours.get_mut(handle.0 as usize) {
+            Some(Some(contour)) => {
+                *contour = read_contour(size, vertices);
+                inc.dirty[handle.0 as usize] = true;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Stop keeping a contour.  Returns `false` if it was already removed.
+    pub fn remove_contour(&mut self, handle: ContourHandle) -> bool {
+        match self.incremental.contours.get_mut(handle.0 as usize) {
+            Some(contour) => contour.take().is_some(),
+            None => false,
+        }
+    }
+
+    /// Number of kept contours the last `retessellate` had to sweep again.
+    pub fn resweep_count(&self) -> usize {
+        self.incremental.resweeps
+    }
+
+    /// Tessellate the kept contours, re-sweeping only the clusters touched
+    /// since the last call (see the module docs).  Arguments are as for
+    /// `tessellate` and the output is the same triangle set, in a different
+    /// order; `vertex_indices()` numbers the kept contours' vertices in
+    /// handle order, as a full run adding them in that order would.  Input
+    /// pending from `add_contour` is left in place; pending `add_points` /
+    /// `add_constraint` input or `TessOption::Voronoi` fail the call with
+    /// `TessStatus::InvalidInput`.  `mesh()` is empty afterwards.
+    pub fn retessellate(
+        &mut self,
+        winding_rule: WindingRule,
+        element_type: ElementType,
+        poly_size: usize,
+        vertex_size: usize,
+        normal: Option<[Real; 3]>,
+    ) -> bool {
+        if self.status != TessStatus::Ok {
+            return false;
+        }
+        if !self.steiner_points.is_empty() || !self.constraints.is_empty() || self.voronoi {
+            self.status = TessStatus::InvalidInput;
+            return false;
+        }
+        // The kept contours are checked as a whole; cluster runs skip it.
+        let tolerance = self.planarity_tolerance;
+        if tolerance > 0.0 {
+            let contours = self.incremental.contours.iter().flatten();
+            let edges = contours
+                .clone()
+                .flat_map(|c| (0..c.len()).map(move |j| (c[j], c[(j + 1) % c.len()])));
+            let points = contours.flatten().copied();
+            if fit_plane(edges, points).is_some_and(|fit| fit.max_deviation > tolerance) {
+                self.status = TessStatus::NonPlanar;
+                return false;
+            }
+        }
+        self.planarity_tolerance = 0.0;
+        let mut inc = std::mem::take(&mut self.incremental);
+        let ok = self.retessellate_with(
+            &mut inc,
+            winding_rule,
+            element_type,
+            poly_size,
+            vertex_size.clamp(2, 3),
+            normal,
+        );
+        self.incremental = inc;
+        self.planarity_tolerance = tolerance;
+        ok
+    }
+
+    /// The projection a full run over the kept contours would pick: the
+    /// normal to pass to each cluster's run (pointing backwards when the
+    /// full run would mirror t), and the effective normal.
+    fn kept_projection(
+        &self,
+        inc: &Incremental,
+        normal: Option<[Real; 3]>,
+    ) -> ([Real; 3], [Real; 3]) {
+        if let Some(n) = normal.filter(|n| *n != [0.0; 3]) {
+            return (n, n);
+        }
+        let points = inc.contours.iter().flatten().flatten().copied();
+        let mut norm = [0.0; 3];
+        compute_normal_of(points, &mut norm);
+        let i = long_axis(&norm);
+        if norm[i] < 0.0 {
+            norm = [-norm[0], -norm[1], -norm[2]];
+        }
+        let (su, tu) = projection_axes(&norm, self.orthonormal_projection);
+        // Mirrors `check_orientation`: mirror t when the contours wind
+        // clockwise overall.
+        let mut area = 0.0;
+        for c in inc.contours.iter().flatten() {
+            for (j, p) in c.iter().enumerate() {
+                let q = c[(j + 1) % c.len()];
+                area += (dot(p, &su) - dot(&q, &su)) * (dot(p, &tu) + dot(&q, &tu));
+            }
+        }
+        if self.reverse_contours {
+            area = -area;
+        }
+        let sign = if area < 0.0 { -1.0 } else { 1.0 };
+        // Only the axis and its direction matter to the axis-aligned
+        // projection; passing the bare axis keeps cached clusters valid as
+        // the contours change.  The orthonormal basis follows the normal
+        // itself, so any edit then re-sweeps every cluster.
+        let pass = if self.orthonormal_projection {
+            [sign * norm[0], sign * norm[1], sign * norm[2]]
+        } else {
+            let mut axis = [0.0; 3];
+            axis[i] = sign;
+            axis
+        };
+        (pass, norm)
+    }
+
+    fn retessellate_with(
+        &mut self,
+        inc: &mut Incremental,
+        winding_rule: WindingRule,
+        element_type: ElementType,
+        poly_size: usize,
+        vertex_size: usize,
+        normal: Option<[Real; 3]>,
+    ) -> bool {
+        let (pass_normal, effective) = self.kept_projection(inc, normal);
+        let key = RunKey {
+            winding_rule,
+            element_type,
+            poly_size,
+            vertex_size,
+            normal: pass_normal,
+            flags: [
+                self.process_cdt,
+                self.exact_predicates,
+                self.reverse_contours,
+                self.orthonormal_projection,
+            ],
+            contour_orientation: self.contour_orientation,
+            settings: [
+                self.min_angle,
+                self.max_area,
+                self.weld_epsilon,
+                self.snap_grid,
+            ],
+        };
+        if inc.key.as_ref() != Some(&key) {
+            inc.clusters.clear();
+            inc.key = Some(key);
+        }
+
+        let (su, tu) = projection_axes(&pass_normal, self.orthonormal_projection);
+        let boxes: Vec<(u32, [Real; 4])> = inc
+            .contours
+            .iter()
+            .enumerate()
+            .filter_map(|(h, c)| {
+                let c = c.as_ref().filter(|c| !c.is_empty())?;
+                let mut b = [
+                    Real::INFINITY,
+                    Real::INFINITY,
+                    Real::NEG_INFINITY,
+                    Real::NEG_INFINITY,
+                ];
+                for p in c {
+                    let (s, t) = (dot(p, &su), dot(p, &tu));
+                    b = [b[0].min(s), b[1].min(t), b[2].max(s), b[3].max(t)];
+                }
+                Some((h as u32, b))
+            })
+            .collect();
+
+        // Sweep the new and changed clusters with the pending input set aside.
+        // A full run mirrors t when the contours wind clockwise overall;
+        // cluster runs get the same projection from the negated normal, so
+        // their ring orientations come out relative to it and are turned
+        // back in `splice_clusters`.
+        let flipped = dot(&pass_normal, &effective) < 0.0;
+        let orientation = self.contour_orientation;
+        if flipped {
+            self.contour_orientation = orientation.map(Orientation::reversed);
+        }
+        let pending = (self.mesh.take(), self.vertex_index_counter);
+        let mut old = std::mem::take(&mut inc.clusters);
+        let mut ok = true;
+        inc.resweeps = 0;
+        for members in clusters_of(&boxes) {
+            let cached = old.iter().position(|c| c.members == members);
+            let cluster = match cached {
+                Some(k) if !members.iter().any(|&h| inc.dirty[h as usize]) => old.swap_remove(k),
+                _ => {
+                    inc.resweeps += members.len();
+                    match self.sweep_cluster(
+                        inc,
+                        members,
+                        winding_rule,
+                        element_type,
+                        poly_size,
+                        vertex_size,
+                        pass_normal,
+                    ) {
+                        Some(cluster) => cluster,
+                        None => {
+                            ok = false;
+                            break;
+                        }
+                    }
+                }
+            };
+            inc.clusters.push(cluster);
+        }
+        (self.mesh, self.vertex_index_counter) = pending;
+        self.contour_orientation = orientation;
+        // A kept mesh would only hold the last cluster swept.
+        self.release_kept_mesh();
+        if !ok {
+            inc.clusters.clear();
+            return false;
+        }
+        inc.dirty.iter_mut().for_each(|d| *d = false);
+        self.splice_clusters(inc, element_type, flipped);
+        self.normal = effective;
+
+        // The projection and bounds of the full run, for `out_uvs`.
+        (self.s_unit, self.t_unit) = (su, tu);
+        if let Some(&(_, first)) = boxes.first() {
+            let b = boxes.iter().fold(first, |a, (_, b)| {
+                [a[0].min(b[0]), a[1].min(b[1]), a[2].max(b[2]), a[3].max(b[3])]
+            });
+            self.bmin = [b[0], b[1]];
+            self.bmax = [b[2], b[3]];
+        }
+        self.output_uvs();
+        true
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn sweep_cluster(
+        &mut self,
+        inc: &Incremental,
+        members: Vec<u32>,
+        winding_rule: WindingRule,
+        element_type: ElementType,
+        poly_size: usize,
+        vertex_size: usize,
+        normal: [Real; 3],
+    ) -> Option<Cluster> {
+        let mut next = 0;
+        for &h in &members {
+            let contour = inc.contours[h as usize].as_ref().unwrap();
+            let flat: Vec<Real> = contour.iter().flatten().copied().collect();
+            let indices: Vec<u32> = (next..next + contour.len() as u32).collect();
+            next += contour.len() as u32;
+            self.add_contour_indexed(3, &flat, Some(&indices));
+        }
+        if !self.tessellate(
+            winding_rule,
+            element_type,
+            poly_size,
+            vertex_size,
+            Some(normal),
+        ) {
+            return None;
+        }
+        Some(Cluster {
+            members,
+            vertices: std::mem::take(&mut self.out_vertices),
+            projected: std::mem::take(&mut self.out_projected),
+            vertex_indices: std::mem::take(&mut self.out_vertex_indices),
+            elements: std::mem::take(&mut self.out_elements),
+            edge_flags: std::mem::take(&mut self.out_edge_flags),
+            contour_tree: std::mem::take(&mut self.out_contour_tree),
+            vertex_count: self.out_vertex_count,
+            element_count: self.out_element_count,
+        })
+    }
+
+    /// Concatenate the cluster outputs, shifting vertex, element and ring
+    /// references and renumbering vertex indices to handle order.
+    fn splice_clusters(&mut self, inc: &Incremental, element_type: ElementType, flipped: bool) {
+        // First global vertex index of each kept contour.
+        let mut base = vec![0u32; inc.contours.len()];
+        let mut next = 0;
+        for (h, c) in inc.contours.iter().enumerate() {
+            base[h] = next;
+            next += c.as_ref().map_or(0, |c| c.len() as u32);
+        }
+
+        self.out_vertices.clear();
+        self.out_projected.clear();
+        self.out_vertex_indices.clear();
+        self.out_elements.clear();
+        self.out_edge_flags.clear();
+        self.out_contour_tree.clear();
+        self.out_voronoi_vertices.clear();
+        self.out_voronoi_cells.clear();
+        self.out_voronoi_indices.clear();
+        let (mut vertex_count, mut element_count) = (0, 0);
+        for cluster in &inc.clusters {
+            let mut starts = Vec::with_capacity(cluster.members.len());
+            let mut local = 0;
+            for &h in &cluster.members {
+                starts.push(local);
+                local += inc.contours[h as usize].as_ref().unwrap().len() as u32;
+            }
+            self.out_vertices.extend_from_slice(&cluster.vertices);
+            self.out_projected.extend_from_slice(&cluster.projected);
+            self.out_vertex_indices
+                .extend(cluster.vertex_indices.iter().map(|&idx| {
+                    if idx == TESS_UNDEF {
+                        return idx;
+                    }
+                    let k = starts.partition_point(|&s| s <= idx) - 1;
+                    base[cluster.members[k] as usize] + idx - starts[k]
+                }));
+            self.out_edge_flags.extend_from_slice(&cluster.edge_flags);
+
+            let (v0, e0) = (vertex_count as u32, element_count as u32);
+            let shift = |x: u32, by: u32| if x == TESS_UNDEF { x } else { x + by };
+            match element_type {
+                ElementType::ConnectedPolygons => {
+                    let stride = cluster.elements.len() / cluster.element_count.max(1);
+                    for poly in cluster.elements.chunks(stride.max(1)) {
+                        let (verts, neighbours) = poly.split_at(stride / 2);
+                        self.out_elements
+                            .extend(verts.iter().map(|&x| shift(x, v0)));
+                        self.out_elements
+                            .extend(neighbours.iter().map(|&x| shift(x, e0)));
+                    }
+                }
+                ElementType::BoundaryContours | ElementType::ConvexPartition => {
+                    for pair in cluster.elements.chunks(2) {
+                        self.out_elements.extend([pair[0] + v0, pair[1]]);
+                    }
+                }
+                _ => self
+                    .out_elements
+                    .extend(cluster.elements.iter().map(|&x| shift(x, v0))),
+            }
+            let r0 = self.out_contour_tree.len();
+            self.out_contour_tree
+                .extend(cluster.contour_tree.iter().map(|node| ContourNode {
+                    parent: node.parent.map(|p| p + r0),
+                    children: node.children.iter().map(|c| c + r0).collect(),
+                    orientation: if flipped {
+                        node.orientation.reversed()
+                    } else {
+                        node.orientation
+                    },
+                    ..node.clone()
+                }));
+            vertex_count += cluster.vertex_count;
+            element_count += cluster.element_count;
+        }
+        self.out_vertex_count = vertex_count;
+        self.out_element_count = element_count;
+    }
+}
@@ -9,22 +9,40 @@
 
 mod api;
 mod connect;
+mod contour_tree;
 mod dirty_regions;
+mod faces;
 mod geometry;
+mod incremental;
 mod output;
+mod planarity;
+mod points;
 mod priority_queue;
+mod projection;
 mod region;
+mod snap;
+mod steiner;
 mod sweep;
+mod uv;
+mod voronoi;
+mod weld;
+mod winding;
 #[cfg(test)]
 mod tests;
 
 pub use api::TessellatorApi;
+pub use contour_tree::{ContourNode, Orientation, PolygonWithHoles};
+pub use faces::{BrepFace, FaceError, FaceMesh};
+pub use incremental::ContourHandle;
+pub use planarity::PlaneFit;
+pub use uv::UvMapping;
+pub use voronoi::VoronoiCell;
 
-use geometry::{check_orientation, compute_normal, dot, is_valid_coord, long_axis};
+use geometry::is_valid_coord;
 
 use crate::dict::Dict;
 use crate::geom::{vert_eq, Real};
-use crate::mesh::{Mesh, VertIdx, E_HEAD, INVALID, V_HEAD};
+use crate::mesh::{EdgeIdx, Mesh, MeshReport, PartitionScratch, VertIdx, VoronoiCells, E_HEAD, INVALID};
 use crate::sweep::ActiveRegion;
 
 // ─────────────────────────────── Public types ──────────────────────────────────
@@ -43,12 +61,42 @@ pub enum ElementType {
     Polygons,
     ConnectedPolygons,
     BoundaryContours,
+    /// Unique mesh edges as `[from, to]` vertex-index pairs, with one
+    /// boundary/interior flag per edge in `out_edge_flags`.
+    EdgeList,
+    /// A near-minimal set of convex pieces with no vertex cap, laid out
+    /// like `BoundaryContours`: `[start, count]` pairs into `vertices()`,
+    /// one counter-clockwise ring per piece.  `out_edge_flags` holds one
+    /// flag per emitted vertex for the edge to the next vertex of its piece.
+    ConvexPartition,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum TessOption {
     ConstrainedDelaunayTriangulation,
     ReverseContours,
+    /// Also output the Voronoi cells of the triangulation's vertices (see
+    /// [`Tessellator::voronoi_cells`]).  Implies Delaunay refinement.
+    Voronoi,
+    /// Decide the sweep's orientation tests with adaptive-precision
+    /// arithmetic (see [`crate::predicates`]) so near-collinear input can
+    /// never flip a topological decision.  Off by default: the default
+    /// arithmetic matches libtess2 bit for bit.
+    ExactPredicates,
+    /// Run [`Mesh::check`](crate::mesh::Mesh::check) after every sweep
+    /// event and stop with `TessStatus::MeshCheckFailed` on the first
+    /// broken invariant, so mesh corruption is caught at the event that
+    /// caused it; [`Tessellator::mesh_report`] then holds the report.
+    /// Debug builds only; ignored in release builds.
+    CheckMesh,
+    /// Keep the finished half-edge mesh after `tessellate` for
+    /// [`Tessellator::mesh`] instead of recycling it.
+    KeepMesh,
+    /// Project onto an orthonormal basis of the normal's plane instead of
+    /// the nearest coordinate plane, so tilted 3D faces are not sheared:
+    /// Delaunay and quality refinement then see their true angles.  No
+    /// change for 2D input.
+    OrthonormalProjection,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -56,6 +104,12 @@ pub enum TessStatus {
     Ok,
     OutOfMemory,
     InvalidInput,
+    /// The contours stray further from their best-fit plane than the
+    /// tolerance set with [`Tessellator::set_planarity_tolerance`].
+    NonPlanar,
+    /// `TessOption::CheckMesh` found a broken mesh invariant; see
+    /// [`Tessellator::mesh_report`].
+    MeshCheckFailed,
 }
 
 pub const TESS_UNDEF: u32 = u32::MAX;
@@ -71,6 +125,19 @@ type RegionIdx = u32;
 
 pub struct Tessellator {
     mesh: Option<Mesh>,
+    /// The last mesh, kept after `tessellate` so the next one reuses its
+    /// arenas.
+    spare_mesh: Option<Mesh>,
+    /// Candidate meshes and buffers of `ElementType::ConvexPartition`.
+    partition_scratch: PartitionScratch,
+    /// Edge stack of Delaunay refinement and the cells of
+    /// `TessOption::Voronoi`, kept for the next run.
+    delaunay_stack: Vec<EdgeIdx>,
+    voronoi_cells: VoronoiCells,
+    /// The finished mesh of the last `tessellate` with `TessOption::KeepMesh`.
+    kept_mesh: Option<Mesh>,
+    /// The failed `TessOption::CheckMesh` report of the last run.
+    mesh_report: Option<MeshReport>,
     pub status: TessStatus,
     normal: [Real; 3],
     s_unit: [Real; 3],
@@ -78,7 +145,19 @@ pub struct Tessellator {
     bmin: [Real; 2],
     bmax: [Real; 2],
     process_cdt: bool,
+    voronoi: bool,
+    exact_predicates: bool,
+    check_mesh: bool,
+    keep_mesh: bool,
+    orthonormal_projection: bool,
     reverse_contours: bool,
+    contour_orientation: Option<Orientation>,
+    min_angle: Real,
+    max_area: Real,
+    weld_epsilon: Real,
+    snap_grid: Real,
+    planarity_tolerance: Real,
+    uv_mapping: Option<UvMapping>,
     winding_rule: WindingRule,
 
     // Sweep state
@@ -97,6 +176,9 @@ pub struct Tessellator {
 
     // Output
     pub out_vertices: Vec<Real>,
+    /// Texture coordinates, two per output vertex, when a mapping is set
+    /// (see [`Tessellator::set_uv_mapping`]).
+    pub out_uvs: Vec<Real>,
     pub out_vertex_indices: Vec<u32>,
     pub out_elements: Vec<u32>,
     /// Per triangle-vertex edge-flag (parallel to `out_elements`).
@@ -114,22 +196,59 @@ pub struct Tessellator {
     /// Populated for `ElementType::Polygons` and `ElementType::ConnectedPolygons`;
     /// empty for `ElementType::BoundaryContours` (no triangles are emitted in
     /// that mode).  Length equals `poly_size × element_count`.
+    ///
+    /// For `ElementType::EdgeList` there is one flag per emitted edge
+    /// instead: `1` for a boundary edge, `0` for an interior diagonal.
+    /// For `ElementType::ConvexPartition` there is one flag per emitted
+    /// vertex, for the piece edge leaving it.
     pub out_edge_flags: Vec<u8>,
     pub out_vertex_count: usize,
     pub out_element_count: usize,
+    /// Ring nesting for `ElementType::BoundaryContours` (see
+    /// [`Tessellator::contour_tree`]); empty for every other element type.
+    pub out_contour_tree: Vec<ContourNode>,
+    /// Child lists of cleared contour tree nodes, kept for their capacity.
+    spare_children: Vec<Vec<usize>>,
+    /// Projected (s, t) of every output vertex, parallel to `out_vertices`.
+    out_projected: Vec<[Real; 2]>,
+    /// Voronoi cell corners, `vertex_size` components each, when
+    /// `TessOption::Voronoi` is set.
+    pub out_voronoi_vertices: Vec<Real>,
+    pub out_voronoi_cells: Vec<VoronoiCell>,
+    /// Corner indices of every cell, concatenated (see [`VoronoiCell`]).
+    pub out_voronoi_indices: Vec<u32>,
+    /// Epsilon merges made by the last `tessellate` (see `set_weld_epsilon`).
+    pub out_weld_count: usize,
+    /// `add_points` points the last `tessellate` dropped because they fell
+    /// outside the fill or on top of an existing vertex.
+    pub out_dropped_points: usize,
     vertex_index_counter: u32,
+    /// Points from `add_points`, inserted after interior tessellation.
+    steiner_points: Vec<([Real; 3], u32)>,
+    /// Polylines from `add_constraint`, inserted after the points.
+    constraints: Vec<Vec<([Real; 3], u32)>>,
 
     // Primary event queue: pre-sorted vertices for the initial sweep phase
     sorted_events: Vec<VertIdx>,
+    /// Scratch for sorting `sorted_events`, kept for its capacity.
+    event_keys: Vec<(Real, Real, VertIdx)>,
     sorted_event_pos: usize,
     sweep_event_num: u32,
     trace_enabled: bool,
+    /// Contours kept for `retessellate`, with their cached cluster output.
+    incremental: incremental::Incremental,
 }
 
 impl Tessellator {
     pub fn new() -> Self {
         Tessellator {
             mesh: None,
+            spare_mesh: None,
+            partition_scratch: PartitionScratch::default(),
+            delaunay_stack: Vec::new(),
+            voronoi_cells: VoronoiCells::default(),
+            kept_mesh: None,
+            mesh_report: None,
             status: TessStatus::Ok,
             normal: [0.0; 3],
             s_unit: [0.0; 3],
@@ -137,7 +256,19 @@ impl Tessellator {
             bmin: [0.0; 2],
             bmax: [0.0; 2],
             process_cdt: false,
+            voronoi: false,
+            exact_predicates: false,
+            check_mesh: false,
+            keep_mesh: false,
+            orthonormal_projection: false,
             reverse_contours: false,
+            contour_orientation: None,
+            min_angle: 0.0,
+            max_area: 0.0,
+            weld_epsilon: 0.0,
+            snap_grid: 0.0,
+            planarity_tolerance: 0.0,
+            uv_mapping: None,
             winding_rule: WindingRule::Odd,
             dict: Dict::new(),
             intersection_verts: Vec::new(),
@@ -148,16 +279,29 @@ impl Tessellator {
             regions: Vec::new(),
             region_free: Vec::new(),
             out_vertices: Vec::new(),
+            out_uvs: Vec::new(),
             out_vertex_indices: Vec::new(),
             out_elements: Vec::new(),
             out_edge_flags: Vec::new(),
             out_vertex_count: 0,
             out_element_count: 0,
+            out_contour_tree: Vec::new(),
+            spare_children: Vec::new(),
+            out_projected: Vec::new(),
+            out_voronoi_vertices: Vec::new(),
+            out_voronoi_cells: Vec::new(),
+            out_voronoi_indices: Vec::new(),
+            out_weld_count: 0,
+            out_dropped_points: 0,
             vertex_index_counter: 0,
+            steiner_points: Vec::new(),
+            constraints: Vec::new(),
             sorted_events: Vec::new(),
+            event_keys: Vec::new(),
             sorted_event_pos: 0,
             sweep_event_num: 0,
             trace_enabled: std::env::var("TESS_TRACE").is_ok(),
+            incremental: incremental::Incremental::default(),
         }
     }
 
@@ -165,22 +309,57 @@ impl Tessellator {
         match option {
             TessOption::ConstrainedDelaunayTriangulation => self.process_cdt = value,
             TessOption::ReverseContours => self.reverse_contours = value,
+            TessOption::Voronoi => self.voronoi = value,
+            TessOption::ExactPredicates => self.exact_predicates = value,
+            TessOption::CheckMesh => self.check_mesh = value,
+            TessOption::KeepMesh => self.keep_mesh = value,
+            TessOption::OrthonormalProjection => self.orthonormal_projection = value,
         }
     }
 
+    /// Fix the vertex order of `BoundaryContours` rings: outer boundaries
+    /// wind in `orientation` around the effective normal and holes wind the
+    /// other way.  `None` (the default) keeps the order the sweep produces.
+    pub fn set_contour_orientation(&mut self, orientation: Option<Orientation>) {
+        self.contour_orientation = orientation;
+    }
+
+    /// Refine triangle output until no triangle has an angle below
+    /// `degrees`, inserting Steiner points (circumcenters and segment
+    /// midpoints) as needed.  Bounds up to ~20.7° always terminate; larger
+    /// ones usually do.  Angles present in the input are left alone.
+    /// `0.0` (the default) disables the bound.  Implies Delaunay flipping.
+    /// Ignored while snap rounding (see `set_snap_grid`).
+    pub fn set_min_angle(&mut self, degrees: Real) {
+        self.min_angle = degrees.max(0.0);
+    }
+
+    /// Refine triangle output until no triangle is larger than `area`,
+    /// measured in the projection plane.  `0.0` (the default) disables the
+    /// bound.  Implies Delaunay flipping.  Ignored while snap rounding.
+    pub fn set_max_area(&mut self, area: Real) {
+        self.max_area = area.max(0.0);
+    }
+
     /// Add a contour. `size` = 2 or 3 (coords per vertex). `vertices` is flat.
     ///
     /// Input type is `Real` — currently `f64` — to avoid losing precision on
     /// coordinate input.  Callers holding `f32` data should cast element-wise
     /// at the call site.
     pub fn add_contour(&mut self, size: usize, vertices: &[Real]) {
+        self.add_contour_indexed(size, vertices, None);
+    }
+
+    /// `add_contour` with explicit `vertex_indices()` values for the
+    /// contour's vertices instead of the running input counter.
+    fn add_contour_indexed(&mut self, size: usize, vertices: &[Real], indices: Option<&[u32]>) {
         if self.status != TessStatus::Ok {
             return;
         }
         let size = size.min(3).max(2);
         let count = vertices.len() / size;
         if self.mesh.is_none() {
-            self.mesh = Some(Mesh::new());
+            self.mesh = Some(self.take_mesh());
         }
 
         let mut e = INVALID;
@@ -224,8 +403,13 @@ impl Tessellator {
             mesh.verts[org as usize].coords[0] = cx;
             mesh.verts[org as usize].coords[1] = cy;
             mesh.verts[org as usize].coords[2] = cz;
-            mesh.verts[org as usize].idx = self.vertex_index_counter;
-            self.vertex_index_counter += 1;
+            mesh.verts[org as usize].idx = match indices {
+                Some(indices) => indices[i],
+                None => {
+                    self.vertex_index_counter += 1;
+                    self.vertex_index_counter - 1
+                }
+            };
 
             let w = if self.reverse_contours { -1 } else { 1 };
             mesh.edges[e as usize].winding = w;
@@ -240,27 +424,65 @@ impl Tessellator {
         poly_size: usize,
         vertex_size: usize,
         normal: Option<[Real; 3]>,
+    ) -> bool {
+        // A failed run must not leave the previous run's mesh behind.
+        self.release_kept_mesh();
+        let ok = self.tessellate_pending(winding_rule, element_type, poly_size, vertex_size, normal);
+        // Points and constraints belong to this run, whether it worked or not.
+        self.steiner_points.clear();
+        self.constraints.clear();
+        ok
+    }
+
+    /// `tessellate` without dropping the pending points and constraints.
+    fn tessellate_pending(
+        &mut self,
+        winding_rule: WindingRule,
+        element_type: ElementType,
+        poly_size: usize,
+        vertex_size: usize,
+        normal: Option<[Real; 3]>,
     ) -> bool {
         if self.status != TessStatus::Ok {
             return false;
         }
         self.winding_rule = winding_rule;
         self.out_vertices.clear();
+        self.out_uvs.clear();
+        self.out_projected.clear();
         self.out_vertex_indices.clear();
         self.out_elements.clear();
         self.out_edge_flags.clear();
+        self.clear_contour_tree();
+        self.out_voronoi_vertices.clear();
+        self.out_voronoi_cells.clear();
+        self.out_voronoi_indices.clear();
+        self.out_weld_count = 0;
+        self.out_dropped_points = 0;
         self.out_vertex_count = 0;
         self.out_element_count = 0;
+        self.mesh_report = None;
         self.normal = normal.unwrap_or([0.0, 0.0, 0.0]);
 
         if self.mesh.is_none() {
-            self.mesh = Some(Mesh::new());
+            self.mesh = Some(self.take_mesh());
         }
+        self.mesh.as_mut().unwrap().exact_predicates = self.exact_predicates;
 
+        if !self.check_planarity() {
+            return false;
+        }
+        if self.snap_grid > 0.0 && !self.snap_round() {
+            self.status = TessStatus::OutOfMemory;
+            return false;
+        }
         if !self.project_polygon() {
             self.status = TessStatus::OutOfMemory;
             return false;
         }
+        if self.snap_grid > 0.0 && !self.check_snap_plane() {
+            return false;
+        }
 
         if !self.compute_interior() {
             if self.status == TessStatus::Ok {
@@ -269,15 +491,137 @@ impl Tessellator {
             return false;
         }
 
+        if !self.prepare_output_faces(element_type) {
+            if self.status == TessStatus::Ok {
+                self.status = TessStatus::OutOfMemory;
+            }
+            return false;
+        }
+
+        // Cells are read off the triangles before output merges them.
+        let voronoi = self.voronoi && element_type != ElementType::BoundaryContours && self.mesh.is_some();
+        if voronoi {
+            self.mesh.as_ref().unwrap().voronoi_cells_with(&mut self.voronoi_cells);
+        }
+
         let vertex_size = vertex_size.min(3).max(2);
+        match element_type {
+            ElementType::BoundaryContours => self.output_contours(vertex_size),
+            ElementType::EdgeList => self.output_edges(poly_size, vertex_size),
+            ElementType::ConvexPartition => self.output_convex_partition(vertex_size),
+            _ => self.output_polymesh(element_type, poly_size, vertex_size),
+        }
+        if self.snap_grid > 0.0 {
+            self.snap_output(vertex_size);
+        }
+        self.output_uvs();
+        if voronoi {
+            self.output_voronoi(vertex_size);
+        }
+
+        self.finish_mesh();
+        self.status == TessStatus::Ok
+    }
+
+    /// Turn the swept mesh into the faces `element_type` is read from.
+    /// Mirrors C tessTesselate: `BoundaryContours` keeps only the edges
+    /// that separate inside from outside, so each ring comes out as one
+    /// face; every other element type triangulates the inside faces and
+    /// then inserts Steiner points and constraints and refines.
+    fn prepare_output_faces(&mut self, element_type: ElementType) -> bool {
+        let mesh = self.mesh.as_mut().unwrap();
         if element_type == ElementType::BoundaryContours {
-            self.output_contours(vertex_size);
+            if !mesh.set_winding_number(1, true) {
+                return false;
+            }
         } else {
-            self.output_polymesh(element_type, poly_size, vertex_size);
+            if !mesh.tessellate_interior() {
+                return false;
+            }
+            if (!self.steiner_points.is_empty() || !self.constraints.is_empty()) && !self.insert_steiner_points() {
+                return false;
+            }
+            let mesh = self.mesh.as_mut().unwrap();
+            let refine = self.snap_grid == 0.0 && (self.min_angle > 0.0 || self.max_area > 0.0);
+            if self.process_cdt || refine || self.voronoi {
+                mesh.refine_delaunay_with(&mut self.delaunay_stack);
+            }
+            if refine && !mesh.refine_quality(self.min_angle, self.max_area) {
+                return false;
+            }
         }
+        true
+    }
 
-        self.mesh = None;
-        self.status == TessStatus::Ok
+    /// Drop pending contours, points and constraints and clear the output,
+    /// keeping every allocation (mesh arenas, sweep state, output vectors)
+    /// for the next round.  Options and settings are kept, vertex indices
+    /// restart at 0 and the status goes back to `Ok`.  With inputs of a
+    /// similar size, a warmed-up tessellator then runs without allocating.
+    pub fn reset(&mut self) {
+        self.recycle_mesh();
+        self.release_kept_mesh();
+        self.steiner_points.clear();
+        self.constraints.clear();
+        self.vertex_index_counter = 0;
+        self.status = TessStatus::Ok;
+        self.mesh_report = None;
+        self.out_vertices.clear();
+        self.out_uvs.clear();
+        self.out_projected.clear();
+        self.out_vertex_indices.clear();
+        self.out_elements.clear();
+        self.out_edge_flags.clear();
+        self.clear_contour_tree();
+        self.out_voronoi_vertices.clear();
+        self.out_voronoi_cells.clear();
+        self.out_voronoi_indices.clear();
+        self.out_weld_count = 0;
+        self.out_dropped_points = 0;
+        self.out_vertex_count = 0;
+        self.out_element_count = 0;
+    }
+
+    /// An empty mesh, on the spare mesh's arenas when there is one.
+    fn take_mesh(&mut self) -> Mesh {
+        match self.spare_mesh.take() {
+            Some(mut mesh) => {
+                mesh.clear();
+                mesh
+            }
+            None => Mesh::new(),
+        }
+    }
+
+    /// Keep the current mesh (if any) as the spare.
+    fn recycle_mesh(&mut self) {
+        if let Some(mesh) = self.mesh.take() {
+            self.spare_mesh = Some(mesh);
+        }
+    }
+
+    /// Hand the finished mesh to `mesh()` when `TessOption::KeepMesh` is
+    /// set, recycling the one kept before; otherwise recycle it.
+    fn finish_mesh(&mut self) {
+        if self.keep_mesh {
+            let mut done = self.mesh.take();
+            if let Some(mesh) = done.as_mut() {
+                mesh.discard_exterior();
+            }
+            self.release_kept_mesh();
+            self.kept_mesh = done;
+        } else {
+            self.recycle_mesh();
+            self.release_kept_mesh();
+        }
+    }
+
+    /// Drop the kept mesh, keeping its arenas as the spare if there is none.
+    fn release_kept_mesh(&mut self) {
+        let kept = self.kept_mesh.take();
+        if self.spare_mesh.is_none() {
+            self.spare_mesh = kept;
+        }
     }
 
     // ─────── Accessors ────────────────────────────────────────────────────────
@@ -285,6 +629,23 @@ impl Tessellator {
     pub fn vertex_count(&self) -> usize {
         self.out_vertex_count
     }
+    /// The finished half-edge mesh of the last `tessellate`, when
+    /// `TessOption::KeepMesh` was set (see the queries in
+    /// [`crate::mesh::Mesh`]).  It is the mesh the output was read from,
+    /// with the faces outside the fill discarded: triangles for `Polygons`
+    /// with `poly_size` 3, merged faces for larger sizes, one face per
+    /// ring for `BoundaryContours`.  `coords` are
+    /// the input coordinates, `s`/`t` the projected ones, `idx` the input
+    /// vertex index and, for `Polygons` and `ConnectedPolygons`, `n` the
+    /// output vertex (or, on faces, element) index.
+    pub fn mesh(&self) -> Option<&Mesh> {
+        self.kept_mesh.as_ref()
+    }
+    /// What `TessOption::CheckMesh` found wrong when the last `tessellate`
+    /// failed with `TessStatus::MeshCheckFailed`.
+    pub fn mesh_report(&self) -> Option<&MeshReport> {
+        self.mesh_report.as_ref()
+    }
     pub fn element_count(&self) -> usize {
         self.out_element_count
     }
@@ -299,7 +660,9 @@ impl Tessellator {
     }
     /// Per triangle-vertex edge flags (see [`Tessellator::out_edge_flags`]).
     ///
-    /// Returns an empty slice for `ElementType::BoundaryContours`.
+    /// Returns an empty slice for `ElementType::BoundaryContours`, one flag
+    /// per edge for `ElementType::EdgeList`, and one flag per vertex for
+    /// `ElementType::ConvexPartition`.
     pub fn edge_flags(&self) -> &[u8] {
         &self.out_edge_flags
     }
@@ -307,72 +670,26 @@ impl Tessellator {
         self.status
     }
 
-    // ─────── Projection ───────────────────────────────────────────────────────
+    // ─────── Main interior computation ───────────────────────────────────────
 
-    fn project_polygon(&mut self) -> bool {
-        let mut norm = self.normal;
-        let mut computed_normal = false;
-        if norm[0] == 0.0 && norm[1] == 0.0 && norm[2] == 0.0 {
-            if let Some(ref m) = self.mesh {
-                compute_normal(m, &mut norm);
-            }
-            computed_normal = true;
+    /// `TessOption::CheckMesh`: keep the report and fail the run if the
+    /// mesh broke an invariant.
+    fn check_mesh_after_event(&mut self) -> bool {
+        let report = self.mesh.as_ref().unwrap().check();
+        if report.is_ok() {
+            return true;
         }
-
-        let i = long_axis(&norm);
-        self.s_unit = [0.0; 3];
-        self.t_unit = [0.0; 3];
-        self.s_unit[(i + 1) % 3] = 1.0;
-        self.t_unit[(i + 2) % 3] = if norm[i] > 0.0 { 1.0 } else { -1.0 };
-        let su = self.s_unit;
-        let tu = self.t_unit;
-
-        if let Some(ref mut mesh) = self.mesh {
-            let mut v = mesh.verts[V_HEAD as usize].next;
-            while v != V_HEAD {
-                let c = mesh.verts[v as usize].coords;
-                mesh.verts[v as usize].s = dot(&c, &su);
-                mesh.verts[v as usize].t = dot(&c, &tu);
-                v = mesh.verts[v as usize].next;
-            }
-            if computed_normal {
-                check_orientation(mesh);
-            }
-
-            let mut first = true;
-            let mut v = mesh.verts[V_HEAD as usize].next;
-            while v != V_HEAD {
-                let vs = mesh.verts[v as usize].s;
-                let vt = mesh.verts[v as usize].t;
-                if first {
-                    self.bmin = [vs, vt];
-                    self.bmax = [vs, vt];
-                    first = false;
-                } else {
-                    if vs < self.bmin[0] {
-                        self.bmin[0] = vs;
-                    }
-                    if vs > self.bmax[0] {
-                        self.bmax[0] = vs;
-                    }
-                    if vt < self.bmin[1] {
-                        self.bmin[1] = vt;
-                    }
-                    if vt > self.bmax[1] {
-                        self.bmax[1] = vt;
-                    }
-                }
-                v = mesh.verts[v as usize].next;
-            }
-        }
-        true
+        self.mesh_report = Some(report);
+        self.status = TessStatus::MeshCheckFailed;
+        false
     }
 
-    // ─────── Main interior computation ───────────────────────────────────────
-
     fn compute_interior(&mut self) -> bool {
         self.sweep_event_num = 0;
 
+        if self.weld_epsilon > 0.0 && !self.weld_vertices() {
+            return false;
+        }
         if !self.remove_degenerate_edges() {
             return false;
         }
@@ -435,12 +752,15 @@ impl Tessellator {
             if !self.sweep_event(v) {
                 return false;
             }
+            if cfg!(debug_assertions) && self.check_mesh && !self.check_mesh_after_event() {
+                return false;
+            }
         }
 
         self.done_edge_dict();
 
         let trace = self.trace_enabled;
-        if let Some(ref mut mesh) = self.mesh {
+        if let Some(ref mesh) = self.mesh {
             if trace {
                 let mut inside = 0u32;
                 let mut outside = 0u32;
@@ -467,12 +787,6 @@ impl Tessellator {
                 }
                 eprintln!("R FACES inside={} outside={}", inside, outside);
             }
-            if !mesh.tessellate_interior() {
-                return false;
-            }
-            if self.process_cdt {
-                mesh.refine_delaunay();
-            }
         }
         true
     }
@@ -2,7 +2,7 @@
 // Output generation methods for the Tessellator.
 
 use super::{ElementType, TessStatus, Tessellator, TESS_UNDEF};
-use crate::mesh::{F_HEAD, INVALID, V_HEAD};
+use crate::mesh::{E_HEAD, F_HEAD, INVALID, V_HEAD};
 
 /// Is the half-edge `e` on the boundary between an inside face and an
 /// outside face (or the void outside the whole mesh)?  That's the tess2
@@ -27,46 +27,11 @@ impl Tessellator {
             }
         }
 
-        let mesh = match self.mesh.as_mut() {
-            Some(m) => m,
+        let (max_vert, max_face) = match self.number_inside_faces() {
+            Some(counts) => counts,
             None => return,
         };
 
-        let mut v = mesh.verts[V_HEAD as usize].next;
-        while v != V_HEAD {
-            mesh.verts[v as usize].n = TESS_UNDEF;
-            v = mesh.verts[v as usize].next;
-        }
-
-        let mut max_vert = 0u32;
-        let mut max_face = 0u32;
-
-        let mut f = mesh.faces[F_HEAD as usize].next;
-        while f != F_HEAD {
-            mesh.faces[f as usize].n = TESS_UNDEF;
-            if !mesh.faces[f as usize].inside {
-                f = mesh.faces[f as usize].next;
-                continue;
-            }
-
-            let e_start = mesh.faces[f as usize].an_edge;
-            let mut e = e_start;
-            loop {
-                let org = mesh.edges[e as usize].org;
-                if mesh.verts[org as usize].n == TESS_UNDEF {
-                    mesh.verts[org as usize].n = max_vert;
-                    max_vert += 1;
-                }
-                e = mesh.edges[e as usize].lnext;
-                if e == e_start {
-                    break;
-                }
-            }
-            mesh.faces[f as usize].n = max_face;
-            max_face += 1;
-            f = mesh.faces[f as usize].next;
-        }
-
         self.out_element_count = max_face as usize;
         self.out_vertex_count = max_vert as usize;
 
@@ -75,30 +40,16 @@ impl Tessellator {
         } else {
             poly_size
         };
-        self.out_elements = vec![TESS_UNDEF; max_face as usize * stride];
-        self.out_vertices = vec![0.0; max_vert as usize * vertex_size];
-        self.out_vertex_indices = vec![TESS_UNDEF; max_vert as usize];
+        self.out_elements.clear();
+        self.out_elements.resize(max_face as usize * stride, TESS_UNDEF);
         // Edge flags run parallel to the *primary* triangle-vertex slice of
         // `out_elements` (length = `max_face * poly_size`), independent of
         // the neighbour-face stride used by `ConnectedPolygons`.
-        self.out_edge_flags = vec![0u8; max_face as usize * poly_size];
+        self.out_edge_flags.clear();
+        self.out_edge_flags.resize(max_face as usize * poly_size, 0);
+        self.write_numbered_vertices(max_vert as usize, vertex_size);
 
         let mesh = self.mesh.as_ref().unwrap();
-        let mut v = mesh.verts[V_HEAD as usize].next;
-        while v != V_HEAD {
-            let n = mesh.verts[v as usize].n;
-            if n != TESS_UNDEF {
-                let base = n as usize * vertex_size;
-                self.out_vertices[base] = mesh.verts[v as usize].coords[0];
-                self.out_vertices[base + 1] = mesh.verts[v as usize].coords[1];
-                if vertex_size > 2 {
-                    self.out_vertices[base + 2] = mesh.verts[v as usize].coords[2];
-                }
-                self.out_vertex_indices[n as usize] = mesh.verts[v as usize].idx;
-            }
-            v = mesh.verts[v as usize].next;
-        }
-
         let mut ep = 0;
         let mut efp = 0; // parallel edge-flag cursor (stride = poly_size)
         let mut f = mesh.faces[F_HEAD as usize].next;
@@ -129,7 +80,7 @@ impl Tessellator {
             for _ in fv..poly_size {
                 self.out_elements[ep] = TESS_UNDEF;
                 // Padding slots inside `out_edge_flags` are already zero
-                // from the initial `vec![0u8; …]`.
+                // from the initial `resize(.., 0)`.
                 ep += 1;
             }
             efp += poly_size;
@@ -163,11 +114,150 @@ impl Tessellator {
         }
     }
 
-    pub(crate) fn output_contours(&mut self, vertex_size: usize) {
-        let mesh = match self.mesh.as_ref() {
-            Some(m) => m,
+    /// Number the vertices and faces of every inside face (`Vertex::n`,
+    /// `Face::n`) in face-list order.  Returns `(vertex_count, face_count)`,
+    /// or `None` when there is no mesh.
+    fn number_inside_faces(&mut self) -> Option<(u32, u32)> {
+        let mesh = self.mesh.as_mut()?;
+
+        let mut v = mesh.verts[V_HEAD as usize].next;
+        while v != V_HEAD {
+            mesh.verts[v as usize].n = TESS_UNDEF;
+            v = mesh.verts[v as usize].next;
+        }
+
+        let mut max_vert = 0u32;
+        let mut max_face = 0u32;
+
+        let mut f = mesh.faces[F_HEAD as usize].next;
+        while f != F_HEAD {
+            mesh.faces[f as usize].n = TESS_UNDEF;
+            if !mesh.faces[f as usize].inside {
+                f = mesh.faces[f as usize].next;
+                continue;
+            }
+
+            let e_start = mesh.faces[f as usize].an_edge;
+            let mut e = e_start;
+            loop {
+                let org = mesh.edges[e as usize].org;
+                if mesh.verts[org as usize].n == TESS_UNDEF {
+                    mesh.verts[org as usize].n = max_vert;
+                    max_vert += 1;
+                }
+                e = mesh.edges[e as usize].lnext;
+                if e == e_start {
+                    break;
+                }
+            }
+            mesh.faces[f as usize].n = max_face;
+            max_face += 1;
+            f = mesh.faces[f as usize].next;
+        }
+        Some((max_vert, max_face))
+    }
+
+    /// Fill `out_vertices` / `out_vertex_indices` / `out_projected` from the
+    /// vertex numbering assigned by [`Self::number_inside_faces`].
+    fn write_numbered_vertices(&mut self, vertex_count: usize, vertex_size: usize) {
+        self.out_vertices.clear();
+        self.out_vertices.resize(vertex_count * vertex_size, 0.0);
+        self.out_vertex_indices.clear();
+        self.out_vertex_indices.resize(vertex_count, TESS_UNDEF);
+        self.out_projected.clear();
+        self.out_projected.resize(vertex_count, [0.0; 2]);
+
+        let mesh = self.mesh.as_ref().unwrap();
+        let mut v = mesh.verts[V_HEAD as usize].next;
+        while v != V_HEAD {
+            let n = mesh.verts[v as usize].n;
+            if n != TESS_UNDEF {
+                let base = n as usize * vertex_size;
+                self.out_vertices[base] = mesh.verts[v as usize].coords[0];
+                self.out_vertices[base + 1] = mesh.verts[v as usize].coords[1];
+                if vertex_size > 2 {
+                    self.out_vertices[base + 2] = mesh.verts[v as usize].coords[2];
+                }
+                self.out_vertex_indices[n as usize] = mesh.verts[v as usize].idx;
+                self.out_projected[n as usize] = [mesh.verts[v as usize].s, mesh.verts[v as usize].t];
+            }
+            v = mesh.verts[v as usize].next;
+        }
+    }
+
+    /// `ElementType::EdgeList`: every edge that borders at least one inside
+    /// face, emitted once as a `[from, to]` vertex pair.  The pair is
+    /// oriented with the inside face on its left; the matching
+    /// `out_edge_flags` entry is `1` for boundary edges and `0` for interior
+    /// diagonals.
+    pub(crate) fn output_edges(&mut self, poly_size: usize, vertex_size: usize) {
+        if poly_size > 3 {
+            if let Some(ref mut mesh) = self.mesh {
+                if !mesh.merge_convex_faces(poly_size) {
+                    self.status = TessStatus::OutOfMemory;
+                    return;
+                }
+            }
+        }
+
+        let max_vert = match self.number_inside_faces() {
+            Some((max_vert, _)) => max_vert,
             None => return,
         };
+        self.out_vertex_count = max_vert as usize;
+        self.write_numbered_vertices(max_vert as usize, vertex_size);
+        self.out_elements.clear();
+        self.out_edge_flags.clear();
+
+        let mesh = self.mesh.as_ref().unwrap();
+        let is_inside = |f: u32| f != INVALID && mesh.faces[f as usize].inside;
+        let mut e = mesh.edges[E_HEAD as usize].next;
+        while e != E_HEAD {
+            let left_inside = is_inside(mesh.edges[e as usize].lface);
+            let right_inside = is_inside(mesh.rface(e));
+            if left_inside || right_inside {
+                let e_in = if left_inside { e } else { e ^ 1 };
+                self.out_elements.push(mesh.verts[mesh.edges[e_in as usize].org as usize].n);
+                self.out_elements.push(mesh.verts[mesh.dst(e_in) as usize].n);
+                self.out_edge_flags.push(if left_inside != right_inside { 1 } else { 0 });
+            }
+            e = mesh.edges[e as usize].next;
+        }
+        self.out_element_count = self.out_edge_flags.len();
+    }
+
+    pub(crate) fn output_contours(&mut self, vertex_size: usize) {
+        if self.mesh.is_none() {
+            return;
+        }
+        self.output_rings(vertex_size, false);
+        self.build_contour_tree(vertex_size);
+    }
+
+    /// `ElementType::ConvexPartition`: merge the triangles into convex
+    /// pieces, then write each piece as a ring.
+    pub(crate) fn output_convex_partition(&mut self, vertex_size: usize) {
+        if let Some(ref mut mesh) = self.mesh {
+            if !mesh.partition_convex_with(&mut self.partition_scratch) {
+                self.status = TessStatus::OutOfMemory;
+                return;
+            }
+            self.output_rings(vertex_size, true);
+        }
+    }
+
+    /// Write every inside face as a ring of consecutive output vertices with
+    /// a `[start, count]` pair in `out_elements`.  With `edge_flags`, one
+    /// boundary flag per vertex is written for the edge leaving it.
+    /// The (s, t) of every emitted vertex go to `out_projected`, and each
+    /// mesh vertex's `n` is set to the first output vertex written for it.
+    fn output_rings(&mut self, vertex_size: usize, edge_flags: bool) {
+        let mesh = self.mesh.as_mut().unwrap();
+        let mut v = mesh.verts[V_HEAD as usize].next;
+        while v != V_HEAD {
+            mesh.verts[v as usize].n = TESS_UNDEF;
+            v = mesh.verts[v as usize].next;
+        }
         let mut total_verts = 0usize;
         let mut total_elems = 0usize;
         let mut f = mesh.faces[F_HEAD as usize].next;
@@ -188,14 +278,18 @@ impl Tessellator {
         }
         self.out_element_count = total_elems;
         self.out_vertex_count = total_verts;
-        self.out_elements = vec![TESS_UNDEF; total_elems * 2];
-        self.out_vertices = vec![0.0; total_verts * vertex_size];
-        self.out_vertex_indices = vec![TESS_UNDEF; total_verts];
-        // No triangles produced in BoundaryContours mode, so edge flags
-        // remain empty (parallel slice would have no meaningful entries).
-        self.out_edge_flags = Vec::new();
+        self.out_elements.clear();
+        self.out_elements.resize(total_elems * 2, TESS_UNDEF);
+        self.out_vertices.clear();
+        self.out_vertices.resize(total_verts * vertex_size, 0.0);
+        self.out_vertex_indices.clear();
+        self.out_vertex_indices.resize(total_verts, TESS_UNDEF);
+        // No triangles are produced here; the flags, when requested, run
+        // parallel to the emitted vertices instead.
+        self.out_edge_flags.clear();
+        self.out_projected.clear();
 
-        let mesh = self.mesh.as_ref().unwrap();
+        let mesh = self.mesh.as_mut().unwrap();
         let mut vp = 0usize;
         let mut ep = 0usize;
         let mut sv = 0usize;
@@ -217,6 +311,13 @@ impl Tessellator {
                     self.out_vertices[base + 2] = mesh.verts[org as usize].coords[2];
                 }
                 self.out_vertex_indices[vp] = mesh.verts[org as usize].idx;
+                if mesh.verts[org as usize].n == TESS_UNDEF {
+                    mesh.verts[org as usize].n = vp as u32;
+                }
+                self.out_projected.push([mesh.verts[org as usize].s, mesh.verts[org as usize].t]);
+                if edge_flags {
+                    self.out_edge_flags.push(is_boundary_edge(mesh, e) as u8);
+                }
                 vp += 1;
                 vc += 1;
                 e = mesh.edges[e as usize].lnext;
@@ -0,0 +1,124 @@
+// Copyright 2025 Lars Brubaker
+// License: SGI Free Software License B (MIT-compatible)
+//
+//! Planarity check for 3D contours (`Tessellator::fit_plane`,
+//! `set_planarity_tolerance`).
+//!
+//! The sweep flattens every vertex onto one plane.  When the contours are
+//! far from planar, parts that are apart in space overlap once flattened
+//! and the output folds over.  The fit takes Newell's normal (the summed
+//! area vector of the contours, robust to noise and concave corners)
+//! through the centroid of the contour vertices, and measures how far the
+//! vertices stray from that plane.  Contours whose area vectors cancel
+//! (a figure eight, collinear points) fall back to the normal `tessellate`
+//! would compute.
+
+use crate::geom::Real;
+use crate::mesh::{E_HEAD, INVALID, V_HEAD};
+
+use super::geometry::{compute_normal_of, dot};
+use super::{TessStatus, Tessellator};
+
+/// A best-fit plane through contour vertices.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PlaneFit {
+    /// Unit normal; the contours wind counter-clockwise around it overall.
+    pub normal: [Real; 3],
+    /// Mean of the contour vertices, a point on the plane.
+    pub centroid: [Real; 3],
+    /// Largest distance of a contour vertex from the plane.
+    pub max_deviation: Real,
+}
+
+impl PlaneFit {
+    /// Signed distance of `p` from the plane, positive on the normal's side.
+    pub fn distance(&self, p: [Real; 3]) -> Real {
+        let c = self.centroid;
+        dot(&[p[0] - c[0], p[1] - c[1], p[2] - c[2]], &self.normal)
+    }
+}
+
+/// Fit a plane to directed contour `edges` and their vertices `points`;
+/// `None` without points.
+pub(super) fn fit_plane<E, P>(edges: E, points: P) -> Option<PlaneFit>
+where
+    E: Iterator<Item = ([Real; 3], [Real; 3])>,
+    P: Iterator<Item = [Real; 3]> + Clone,
+{
+    let mut count = 0.0;
+    let mut centroid = [0.0; 3];
+    for p in points.clone() {
+        count += 1.0;
+        centroid = [centroid[0] + p[0], centroid[1] + p[1], centroid[2] + p[2]];
+    }
+    if count == 0.0 {
+        return None;
+    }
+    let centroid = [centroid[0] / count, centroid[1] / count, centroid[2] / count];
+
+    // Newell's method, about the centroid to keep far-off input exact.
+    let mut n = [0.0; 3];
+    for (a, b) in edges {
+        let (a, b) = (
+            [a[0] - centroid[0], a[1] - centroid[1], a[2] - centroid[2]],
+            [b[0] - centroid[0], b[1] - centroid[1], b[2] - centroid[2]],
+        );
+        n[0] += (a[1] - b[1]) * (a[2] + b[2]);
+        n[1] += (a[2] - b[2]) * (a[0] + b[0]);
+        n[2] += (a[0] - b[0]) * (a[1] + b[1]);
+    }
+    if dot(&n, &n) == 0.0 {
+        compute_normal_of(points.clone(), &mut n);
+    }
+    let len = dot(&n, &n).sqrt();
+    let normal = [n[0] / len, n[1] / len, n[2] / len];
+    let mut fit = PlaneFit { normal, centroid, max_deviation: 0.0 };
+    fit.max_deviation = points.map(|p| fit.distance(p).abs()).fold(0.0, Real::max);
+    Some(fit)
+}
+
+impl Tessellator {
+    /// Reject contours that stray more than `tolerance` from their
+    /// best-fit plane: `tessellate` (and `retessellate`) then fail with
+    /// `TessStatus::NonPlanar` instead of producing folded output.  `0`
+    /// (the default) disables the check.
+    pub fn set_planarity_tolerance(&mut self, tolerance: Real) {
+        self.planarity_tolerance = tolerance.max(0.0);
+    }
+
+    /// Best-fit plane of the contours added since the last `tessellate`
+    /// (see the module docs); `None` when there are none.
+    pub fn fit_plane(&self) -> Option<PlaneFit> {
+        let mesh = self.mesh.as_ref()?;
+        let mut edges = Vec::new();
+        let mut e = mesh.edges[E_HEAD as usize].next;
+        while e != E_HEAD && e != INVALID {
+            let org = mesh.verts[mesh.edges[e as usize].org as usize].coords;
+            let dst = mesh.verts[mesh.dst(e) as usize].coords;
+            match mesh.edges[e as usize].winding {
+                w if w > 0 => edges.push((org, dst)),
+                w if w < 0 => edges.push((dst, org)),
+                _ => {}
+            }
+            e = mesh.edges[e as usize].next;
+        }
+        let mut points = Vec::new();
+        let mut v = mesh.verts[V_HEAD as usize].next;
+        while v != V_HEAD && v != INVALID {
+            points.push(mesh.verts[v as usize].coords);
+            v = mesh.verts[v as usize].next;
+        }
+        fit_plane(edges.into_iter(), points.into_iter())
+    }
+
+    /// Set `TessStatus::NonPlanar` when the pending contours fail the
+    /// tolerance.
+    pub(super) fn check_planarity(&mut self) -> bool {
+        let tolerance = self.planarity_tolerance;
+        if tolerance > 0.0 && self.fit_plane().is_some_and(|fit| fit.max_deviation > tolerance) {
+            self.status = TessStatus::NonPlanar;
+            return false;
+        }
+        true
+    }
+}
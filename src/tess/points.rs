@@ -0,0 +1,130 @@
+// Copyright 2025 Lars Brubaker
+// License: SGI Free Software License B (MIT-compatible)
+//
+//! Delaunay triangulation of scattered points
+//! (`Tessellator::triangulate_points`).
+//!
+//! The convex hull of the points becomes a single contour and every other
+//! point is inserted as a Steiner point with Delaunay flipping switched on.
+//! A constrained Delaunay triangulation of a convex hull is the plain
+//! Delaunay triangulation, so the regular pipeline does the rest.
+
+use crate::geom::Real;
+
+use super::geometry::{compute_normal_of, dot, is_valid_coord, projection_axes};
+use super::{ElementType, TessStatus, Tessellator, WindingRule};
+
+/// Twice the signed area of (a, b, c) in the projection plane.
+fn turn(a: [Real; 2], b: [Real; 2], c: [Real; 2]) -> Real {
+    (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+}
+
+/// Andrew's monotone chain over points sorted by (s, t).  Returns the
+/// positions (into `sorted`) of the strictly convex hull corners.
+fn convex_hull(sorted: &[[Real; 2]]) -> Vec<usize> {
+    let mut hull: Vec<usize> = Vec::with_capacity(sorted.len() + 1);
+    let passes = [
+        (0..sorted.len()).collect::<Vec<_>>(),
+        (0..sorted.len()).rev().collect::<Vec<_>>(),
+    ];
+    for pass in passes {
+        let floor = hull.len();
+        for i in pass {
+            while hull.len() >= floor + 2
+                && turn(sorted[hull[hull.len() - 2]], sorted[hull[hull.len() - 1]], sorted[i]) <= 0.0
+            {
+                hull.pop();
+            }
+            hull.push(i);
+        }
+        hull.pop();
+    }
+    hull
+}
+
+impl Tessellator {
+    /// Delaunay-triangulate a point cloud.  `size` = 2 or 3 (coords per
+    /// point); `points` is flat.  Points with the same projected position
+    /// are merged and report the index of their first occurrence.  The
+    /// output is laid out as for `tessellate(.., ElementType::Polygons, 3,
+    /// vertex_size, normal)`, with `vertex_indices()` giving each vertex's
+    /// position in `points`.  Pending contours, points and constraints are
+    /// discarded.  Fewer than three non-collinear points give empty output.
+    pub fn triangulate_points(
+        &mut self,
+        size: usize,
+        points: &[Real],
+        vertex_size: usize,
+        normal: Option<[Real; 3]>,
+    ) -> bool {
+        if self.status != TessStatus::Ok {
+            return false;
+        }
+        let size = size.clamp(2, 3);
+        let coords: Vec<[Real; 3]> = points
+            .chunks_exact(size)
+            .map(|p| [p[0], p[1], if size > 2 { p[2] } else { 0.0 }])
+            .collect();
+        if !coords.iter().flatten().all(|&c| is_valid_coord(c)) {
+            self.status = TessStatus::InvalidInput;
+            return false;
+        }
+        self.recycle_mesh();
+        self.steiner_points.clear();
+        self.constraints.clear();
+
+        let mut norm = [0.0; 3];
+        match normal {
+            Some(n) if n != [0.0; 3] => norm = n,
+            _ => compute_normal_of(coords.iter().copied(), &mut norm),
+        }
+        let (su, tu) = projection_axes(&norm, self.orthonormal_projection);
+        let project = |c: &[Real; 3]| [dot(c, &su), dot(c, &tu)];
+
+        let mut order: Vec<usize> = (0..coords.len()).collect();
+        order.sort_by(|&a, &b| {
+            let (pa, pb) = (project(&coords[a]), project(&coords[b]));
+            pa[0].total_cmp(&pb[0]).then(pa[1].total_cmp(&pb[1])).then(a.cmp(&b))
+        });
+        order.dedup_by(|b, a| project(&coords[*a]) == project(&coords[*b]));
+        let sorted: Vec<[Real; 2]> = order.iter().map(|&i| project(&coords[i])).collect();
+        let hull = if sorted.len() >= 3 { convex_hull(&sorted) } else { Vec::new() };
+
+        if hull.len() < 3 {
+            self.out_vertices.clear();
+            self.out_uvs.clear();
+            self.out_projected.clear();
+            self.out_vertex_indices.clear();
+            self.out_elements.clear();
+            self.out_edge_flags.clear();
+            self.clear_contour_tree();
+            self.out_voronoi_vertices.clear();
+            self.out_voronoi_cells.clear();
+            self.out_voronoi_indices.clear();
+            self.out_vertex_count = 0;
+            self.out_element_count = 0;
+            return true;
+        }
+
+        let mut on_hull = vec![false; order.len()];
+        let mut contour = Vec::with_capacity(hull.len() * 3);
+        let mut indices = Vec::with_capacity(hull.len());
+        for &h in &hull {
+            on_hull[h] = true;
+            contour.extend_from_slice(&coords[order[h]]);
+            indices.push(order[h] as u32);
+        }
+        self.add_contour_indexed(3, &contour, Some(&indices));
+        for (k, &i) in order.iter().enumerate() {
+            if !on_hull[k] {
+                self.steiner_points.push((coords[i], i as u32));
+            }
+        }
+
+        let process_cdt = self.process_cdt;
+        self.process_cdt = true;
+        let ok = self.tessellate(WindingRule::Odd, ElementType::Polygons, 3, vertex_size, Some(norm));
+        self.process_cdt = process_cdt;
+        ok
+    }
+}
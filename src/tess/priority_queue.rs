@@ -6,7 +6,7 @@
 //! Split out of `tess/mod.rs` (libtess2 priorityq.c usage + the sorted
 //! initial event list).  Operates on the same `Tessellator` state.
 
-use crate::geom::{Real, vert_leq};
+use crate::geom::vert_leq;
 use crate::mesh::{INVALID, V_HEAD, VertIdx};
 use super::{Tessellator};
 
@@ -24,7 +24,9 @@ impl Tessellator {
         }
 
         // Collect (s,t,vert_idx) and sort ascending by vert_leq.
-        let mut vert_coords: Vec<(Real, Real, VertIdx)> = Vec::with_capacity(count);
+        let mut vert_coords = std::mem::take(&mut self.event_keys);
+        vert_coords.clear();
+        vert_coords.reserve(count);
         let mut v = mesh.verts[V_HEAD as usize].next;
         while v != V_HEAD {
             vert_coords.push((mesh.verts[v as usize].s, mesh.verts[v as usize].t, v));
@@ -42,7 +44,8 @@ impl Tessellator {
 
         // Build the sorted event queue. Store each vertex's position as a negative
         // handle (convention: -(index+1)) so that pq_delete can invalidate it.
-        self.sorted_events = vert_coords.iter().map(|&(_, _, v)| v).collect();
+        self.sorted_events.clear();
+        self.sorted_events.extend(vert_coords.iter().map(|&(_, _, v)| v));
         self.sorted_event_pos = 0;
         self.intersection_verts.clear();
         self.next_isect_handle = 0;
@@ -52,6 +55,7 @@ impl Tessellator {
             let handle = -(idx as i32 + 1); // negative → sorted_events slot
             self.mesh.as_mut().unwrap().verts[v as usize].pq_handle = handle;
         }
+        self.event_keys = vert_coords;
 
         true
     }
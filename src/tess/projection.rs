@@ -0,0 +1,126 @@
+// Copyright 2025 Lars Brubaker
+// License: SGI Free Software License B (MIT-compatible)
+//
+//! The plane the last run projected onto (`Tessellator::normal`,
+//! `projection_basis`, `projected_bounds`, `projected_vertices`).
+//!
+//! These are read back from the state `tessellate` leaves behind; nothing
+//! is recomputed.  The projection itself (`project_polygon`) lives here
+//! too.
+
+use crate::geom::Real;
+use crate::mesh::V_HEAD;
+
+use super::geometry::{check_orientation, compute_normal, dot, long_axis, projection_axes};
+use super::Tessellator;
+
+impl Tessellator {
+    /// The unit normal the last `tessellate` (or `retessellate`,
+    /// `triangulate_points`) worked with; zero before the first run.
+    /// Ring orientations in the contour tree are relative to it.
+    pub fn normal(&self) -> [Real; 3] {
+        let n = self.normal;
+        let len = dot(&n, &n).sqrt();
+        if len > 0.0 {
+            [n[0] / len, n[1] / len, n[2] / len]
+        } else {
+            n
+        }
+    }
+
+    /// The `(s, t)` axes of the last projection, as unit 3D vectors.  The
+    /// normal's longest component picks the axis to drop: s is the next
+    /// coordinate axis and t the one after, negated when the normal points
+    /// backwards along the dropped axis or, for a computed normal, when the
+    /// contours wind clockwise in `(s, t)`.  With
+    /// `TessOption::OrthonormalProjection`, s is that axis made
+    /// perpendicular to the normal and t is `normal × s`, mirrored the
+    /// same way.  The sweep runs on `(dot(p, s), dot(p, t))` for every
+    /// vertex `p`.
+    pub fn projection_basis(&self) -> ([Real; 3], [Real; 3]) {
+        (self.s_unit, self.t_unit)
+    }
+
+    /// The input's bounding box in `(s, t)`, as `(min, max)`.
+    pub fn projected_bounds(&self) -> ([Real; 2], [Real; 2]) {
+        (self.bmin, self.bmax)
+    }
+
+    /// `(s, t)` of every output vertex, two per vertex, parallel to
+    /// `vertices()`.  They are the coordinates the sweep used, so 3D input
+    /// written out with `vertex_size` 2 still gets its true projection.
+    pub fn projected_vertices(&self) -> Vec<Real> {
+        self.out_projected.iter().flatten().copied().collect()
+    }
+
+    /// Project every vertex onto `(s, t)` and record the basis, the bounds
+    /// and the effective normal for the accessors above.  Unlike C
+    /// ProjectPolygon, `self.normal` is overwritten with the effective
+    /// normal and `t_unit` is negated along with the vertices when the
+    /// orientation check mirrors them.
+    pub(super) fn project_polygon(&mut self) -> bool {
+        let mut norm = self.normal;
+        let mut computed_normal = false;
+        if norm[0] == 0.0 && norm[1] == 0.0 && norm[2] == 0.0 {
+            if let Some(ref m) = self.mesh {
+                compute_normal(m, &mut norm);
+            }
+            computed_normal = true;
+        }
+
+        let i = long_axis(&norm);
+        (self.s_unit, self.t_unit) = projection_axes(&norm, self.orthonormal_projection);
+        let su = self.s_unit;
+        let tu = self.t_unit;
+
+        // The effective normal: the caller's, or the computed one turned to
+        // point along the positive projection axis (+Z for 2D input).  Ring
+        // orientations are reported relative to it.
+        if computed_normal && norm[i] < 0.0 {
+            norm = [-norm[0], -norm[1], -norm[2]];
+        }
+        self.normal = norm;
+
+        if let Some(ref mut mesh) = self.mesh {
+            let mut v = mesh.verts[V_HEAD as usize].next;
+            while v != V_HEAD {
+                let c = mesh.verts[v as usize].coords;
+                mesh.verts[v as usize].s = dot(&c, &su);
+                mesh.verts[v as usize].t = dot(&c, &tu);
+                v = mesh.verts[v as usize].next;
+            }
+            // Keep `t_unit` in step with the vertices when the orientation
+            // check mirrors them, so `t == dot(coords, t_unit)` always holds.
+            if computed_normal && check_orientation(mesh) {
+                self.t_unit = [-tu[0], -tu[1], -tu[2]];
+            }
+
+            let mut first = true;
+            let mut v = mesh.verts[V_HEAD as usize].next;
+            while v != V_HEAD {
+                let vs = mesh.verts[v as usize].s;
+                let vt = mesh.verts[v as usize].t;
+                if first {
+                    self.bmin = [vs, vt];
+                    self.bmax = [vs, vt];
+                    first = false;
+                } else {
+                    if vs < self.bmin[0] {
+                        self.bmin[0] = vs;
+                    }
+                    if vs > self.bmax[0] {
+                        self.bmax[0] = vs;
+                    }
+                    if vt < self.bmin[1] {
+                        self.bmin[1] = vt;
+                    }
+                    if vt > self.bmax[1] {
+                        self.bmax[1] = vt;
+                    }
+                }
+                v = mesh.verts[v as usize].next;
+            }
+        }
+        true
+    }
+}
@@ -8,11 +8,11 @@
 //! `top_left/right_region` helpers.  The index-keyed `active_region`
 //! invariant lives here (see `delete_region` / `fix_upper_edge`).
 
-use crate::geom::{Real, edge_sign, vert_eq, vert_leq};
+use crate::geom::{Real, edge_sign_fn, vert_eq, vert_leq};
 use crate::mesh::{EdgeIdx, INVALID};
-use crate::dict::{DICT_HEAD, Dict, NodeIdx};
+use crate::dict::{DICT_HEAD, NodeIdx};
 use crate::sweep::ActiveRegion;
-use super::{Tessellator, WindingRule, RegionIdx};
+use super::{Tessellator, RegionIdx};
 
 impl Tessellator {
     // ─────── Edge dictionary initialization ──────────────────────────────────
@@ -109,7 +109,12 @@ impl Tessellator {
     }
 
     pub(super) fn init_edge_dict(&mut self) -> bool {
-        self.dict = Dict::new();
+        self.dict.clear();
+        // Emptied rather than left to grow across calls: the dirty-region
+        // loop bounds itself by `regions.len()`, which must count this
+        // sweep's regions only, as in a fresh tessellator.
+        self.regions.clear();
+        self.region_free.clear();
 
         // Compute sentinel bounds from bounding box + margin (mirrors C InitEdgeDict)
         let w = (self.bmax[0] - self.bmin[0]) + 0.01;
@@ -207,6 +212,7 @@ impl Tessellator {
 
         let ev_s = self.event_s;
         let ev_t = self.event_t;
+        let edge_sign = edge_sign_fn(self.exact_predicates);
 
         let (e1ds, e1dt) = (mesh.verts[e1_dst as usize].s, mesh.verts[e1_dst as usize].t);
         let (e2ds, e2dt) = (mesh.verts[e2_dst as usize].s, mesh.verts[e2_dst as usize].t);
@@ -225,6 +231,12 @@ impl Tessellator {
         if vert_eq(e2ds, e2dt, ev_s, ev_t) {
             return edge_sign(e1ds, e1dt, ev_s, ev_t, e1os, e1ot) >= 0.0;
         }
+        if self.exact_predicates {
+            let cmp = crate::predicates::edge_eval_cmp(
+                [e1ds, e1dt], [e1os, e1ot], [ev_s, ev_t], [e2ds, e2dt], [e2os, e2ot],
+            );
+            return cmp >= 0.0;
+        }
         let t1 = crate::geom::edge_eval(e1ds, e1dt, ev_s, ev_t, e1os, e1ot);
         let t2 = crate::geom::edge_eval(e2ds, e2dt, ev_s, ev_t, e2os, e2ot);
         t1 >= t2
@@ -312,13 +324,7 @@ impl Tessellator {
     }
 
     pub(super) fn is_winding_inside(&self, n: i32) -> bool {
-        match self.winding_rule {
-            WindingRule::Odd => n & 1 != 0,
-            WindingRule::NonZero => n != 0,
-            WindingRule::Positive => n > 0,
-            WindingRule::Negative => n < 0,
-            WindingRule::AbsGeqTwo => n >= 2 || n <= -2,
-        }
+        self.winding_rule.is_inside(n)
     }
 
     pub(super) fn compute_winding(&mut self, reg: RegionIdx) {
@@ -0,0 +1,220 @@
+// Copyright 2025 Lars Brubaker
+// License: SGI Free Software License B (MIT-compatible)
+//
+//! Snap rounding onto a grid (`Tessellator::set_snap_grid`).
+//!
+//! Rounding output vertices after the fact can fold triangles over, because
+//! an edge passing close to a rounded vertex ends up on its other side.
+//! Snap rounding (Hobby; Guibas–Marimont) fixes this before the sweep: every
+//! grid cell ("pixel") holding a contour vertex or a crossing of two contour
+//! edges is hot, and each edge is rerouted through the centres of all hot
+//! pixels it passes through.  The rerouted edges only meet at shared grid
+//! points or along shared pieces, which the sweep merges exactly, so the
+//! fill keeps its topology and every vertex lands on the grid.  Crossings
+//! the sweep still finds between them (from rounding in the rerouting) are
+//! put at the centre of their pixel as they are found.
+//!
+//! Snapping works on the x and y input coordinates, so it needs the sweep
+//! to project onto the x-y plane; z is interpolated along the rerouted
+//! edges.  Steiner points and constraint vertices are moved to
+//! their grid points too.  Quality refinement is skipped, since its Steiner
+//! points cannot be placed on the grid.
+
+use crate::geom::Real;
+use crate::mesh::{EdgeIdx, E_HEAD, V_HEAD};
+
+use super::weld::nearest_within;
+use super::{TessStatus, Tessellator};
+
+type Pixel = (i64, i64);
+
+/// Where segment `a`-`b` enters the closed square `lo`-`hi`, as a parameter
+/// along the segment, when it overlaps it for a positive length (or ends
+/// inside it).
+fn enter(a: [Real; 2], b: [Real; 2], lo: [Real; 2], hi: [Real; 2]) -> Option<Real> {
+    let (mut t0, mut t1): (Real, Real) = (0.0, 1.0);
+    for k in 0..2 {
+        let d = b[k] - a[k];
+        if d == 0.0 {
+            if a[k] < lo[k] || a[k] > hi[k] {
+                return None;
+            }
+        } else {
+            let (ta, tb) = ((lo[k] - a[k]) / d, (hi[k] - a[k]) / d);
+            t0 = t0.max(ta.min(tb));
+            t1 = t1.min(ta.max(tb));
+        }
+    }
+    (t0 < t1 || (t0 == t1 && (t0 == 0.0 || t0 == 1.0))).then_some(t0)
+}
+
+/// Point where segments `p` and `q` cross, endpoints included.
+fn crossing(p: [[Real; 2]; 2], q: [[Real; 2]; 2]) -> Option<[Real; 2]> {
+    let cross = |u: [Real; 2], v: [Real; 2]| u[0] * v[1] - u[1] * v[0];
+    let r = [p[1][0] - p[0][0], p[1][1] - p[0][1]];
+    let s = [q[1][0] - q[0][0], q[1][1] - q[0][1]];
+    let d = cross(r, s);
+    if d == 0.0 {
+        return None;
+    }
+    let w = [q[0][0] - p[0][0], q[0][1] - p[0][1]];
+    let (t, u) = (cross(w, s) / d, cross(w, r) / d);
+    ((0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u)).then(|| [p[0][0] + r[0] * t, p[0][1] + r[1] * t])
+}
+
+impl Tessellator {
+    /// Snap round the output onto a grid of this spacing: every output
+    /// vertex, intersections included, lands on a multiple of `spacing` in
+    /// x and y, without triangles folding over.  `0` (the default) disables
+    /// snapping.  The sweep must then run in x and y: input whose normal
+    /// (given or computed) projects it onto another plane, or onto a tilted
+    /// one with `TessOption::OrthonormalProjection`, fails with
+    /// `TessStatus::InvalidInput`.  `set_min_angle` and `set_max_area` are
+    /// ignored while snapping, as refinement points would be off the grid.
+    pub fn set_snap_grid(&mut self, spacing: Real) {
+        self.snap_grid = spacing.max(0.0);
+    }
+
+    /// Fail with `TessStatus::InvalidInput` unless the projection maps x
+    /// and y straight to s and ±t, the plane `snap_round` worked in.
+    pub(crate) fn check_snap_plane(&mut self) -> bool {
+        let xy = self.s_unit == [1.0, 0.0, 0.0] && (self.t_unit == [0.0, 1.0, 0.0] || self.t_unit == [0.0, -1.0, 0.0]);
+        if !xy {
+            self.status = TessStatus::InvalidInput;
+        }
+        xy
+    }
+
+    fn pixel(&self, p: [Real; 2]) -> Pixel {
+        ((p[0] / self.snap_grid).round() as i64, (p[1] / self.snap_grid).round() as i64)
+    }
+
+    fn pixel_center(&self, p: Pixel) -> [Real; 2] {
+        [p.0 as Real * self.snap_grid, p.1 as Real * self.snap_grid]
+    }
+
+    /// Reroute the contour edges through the hot pixels and move every
+    /// vertex onto the grid (see module docs).
+    pub(crate) fn snap_round(&mut self) -> bool {
+        let mesh = self.mesh.as_ref().unwrap();
+        let xy = |v: u32| {
+            let c = mesh.verts[v as usize].coords;
+            [c[0], c[1]]
+        };
+        let mut segments: Vec<(EdgeIdx, [[Real; 2]; 2])> = Vec::new();
+        let mut e = mesh.edges[E_HEAD as usize].next;
+        while e != E_HEAD {
+            segments.push((e, [xy(mesh.edges[e as usize].org), xy(mesh.dst(e))]));
+            e = mesh.edges[e as usize].next;
+        }
+
+        let mut hot: Vec<Pixel> = Vec::new();
+        let mut v = mesh.verts[V_HEAD as usize].next;
+        while v != V_HEAD {
+            hot.push(self.pixel(xy(v)));
+            v = mesh.verts[v as usize].next;
+        }
+        let min_x = |s: &[[Real; 2]; 2]| s[0][0].min(s[1][0]);
+        let max_x = |s: &[[Real; 2]; 2]| s[0][0].max(s[1][0]);
+        let mut by_x: Vec<&[[Real; 2]; 2]> = segments.iter().map(|(_, s)| s).collect();
+        by_x.sort_by(|a, b| min_x(a).total_cmp(&min_x(b)));
+        for (i, p) in by_x.iter().enumerate() {
+            for q in &by_x[i + 1..] {
+                if min_x(q) > max_x(p) {
+                    break;
+                }
+                if let Some(x) = crossing(**p, **q) {
+                    hot.push(self.pixel(x));
+                }
+            }
+        }
+        hot.sort_unstable();
+        hot.dedup();
+
+        // The hot pixels each edge passes through, in order along it.
+        let half = 0.5 * self.snap_grid;
+        let mut routes: Vec<(EdgeIdx, Vec<(Real, Pixel)>)> = Vec::new();
+        for (e, [a, b]) in &segments {
+            let (first, last) = (self.pixel(*a), self.pixel(*b));
+            let lo = hot.partition_point(|p| p.0 < first.0.min(last.0) - 1);
+            let mut route = Vec::new();
+            for &p in &hot[lo..] {
+                if p.0 > first.0.max(last.0) + 1 {
+                    break;
+                }
+                if p == first || p == last {
+                    continue;
+                }
+                let c = self.pixel_center(p);
+                if let Some(t) = enter(*a, *b, [c[0] - half, c[1] - half], [c[0] + half, c[1] + half]) {
+                    route.push((t, p));
+                }
+            }
+            if !route.is_empty() {
+                route.sort_by(|x, y| x.0.total_cmp(&y.0));
+                route.dedup_by_key(|x| x.1);
+                routes.push((*e, route));
+            }
+        }
+
+        let g = self.snap_grid;
+        let snap = |x: Real| (x / g).round() * g;
+        let mesh = self.mesh.as_mut().unwrap();
+        for (e, route) in routes {
+            let mut e = e;
+            let z0 = mesh.verts[mesh.edges[e as usize].org as usize].coords[2];
+            let z1 = mesh.verts[mesh.dst(e) as usize].coords[2];
+            for (t, p) in route {
+                let Some(e_new) = mesh.split_edge(e) else {
+                    return false;
+                };
+                let w = mesh.edges[e_new as usize].org;
+                mesh.verts[w as usize].coords = [p.0 as Real * g, p.1 as Real * g, z0 + (z1 - z0) * t];
+                e = e_new;
+            }
+        }
+
+        let mut v = mesh.verts[V_HEAD as usize].next;
+        while v != V_HEAD {
+            let c = &mut mesh.verts[v as usize].coords;
+            c[0] = snap(c[0]);
+            c[1] = snap(c[1]);
+            v = mesh.verts[v as usize].next;
+        }
+        for (c, _) in self.steiner_points.iter_mut().chain(self.constraints.iter_mut().flatten()) {
+            c[0] = snap(c[0]);
+            c[1] = snap(c[1]);
+        }
+        true
+    }
+
+    /// Snap a sweep intersection between rerouted edges into its hot
+    /// pixel: onto an endpoint sharing the pixel, or else its centre.  Such
+    /// crossings only come from rounding, as the rerouted edges meet at grid
+    /// points.  Runs before the sweep clamps the intersection, which can
+    /// only move it onto the event or an origin, both on the grid.
+    pub(crate) fn snap_intersection(&self, s: Real, t: Real, targets: [(Real, Real); 3]) -> (Real, Real) {
+        let g = self.snap_grid;
+        if g <= 0.0 {
+            return (s, t);
+        }
+        nearest_within(s, t, &targets, 0.5 * g).unwrap_or(((s / g).round() * g, (t / g).round() * g))
+    }
+
+    /// Round the x and y of every output vertex onto the grid.  Vertices
+    /// are already on it after `snap_round` and `snap_intersection`; this
+    /// settles rounding in the x and y the sweep interpolates for an
+    /// intersection vertex from the endpoints of its edges.
+    pub(crate) fn snap_output(&mut self, vertex_size: usize) {
+        let g = self.snap_grid;
+        for v in self.out_vertices.chunks_mut(vertex_size) {
+            v[0] = (v[0] / g).round() * g;
+            v[1] = (v[1] / g).round() * g;
+        }
+        // s is x and t is y or -y here (`check_snap_plane`).
+        for p in &mut self.out_projected {
+            p[0] = (p[0] / g).round() * g;
+            p[1] = (p[1] / g).round() * g;
+        }
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright 2025 Lars Brubaker
+// License: SGI Free Software License B (MIT-compatible)
+//
+//! Caller-supplied interior points (`Tessellator::add_points`) and
+//! constraint polylines (`Tessellator::add_constraint`).
+//!
+//! Both are held back until the interior has been triangulated, then
+//! projected with the same (s, t) basis as the contours and inserted into
+//! the inside triangles — see `Mesh::insert_points` and
+//! `Mesh::insert_constraint`.
+
+use crate::geom::Real;
+use crate::mesh::SteinerPoint;
+
+use super::geometry::{dot, is_valid_coord};
+use super::{TessStatus, Tessellator};
+
+impl Tessellator {
+    /// Add isolated points that must become vertices of the triangle output
+    /// (terrain samples, sensor locations, ...).  `size` = 2 or 3 (coords
+    /// per point); `points` is flat.  Each point takes the next input vertex
+    /// index, so `vertex_indices()` reports it the same way as contour
+    /// vertices.  Points that fall outside the filled region, or on top of an
+    /// existing vertex, are dropped and counted in `out_dropped_points`.
+    /// Ignored for `BoundaryContours` output.
+    pub fn add_points(&mut self, size: usize, points: &[Real]) {
+        if self.status != TessStatus::Ok {
+            return;
+        }
+        let size = size.clamp(2, 3);
+        for p in points.chunks_exact(size) {
+            let coords = [p[0], p[1], if size > 2 { p[2] } else { 0.0 }];
+            if !coords.iter().all(|&c| is_valid_coord(c)) {
+                self.status = TessStatus::InvalidInput;
+                return;
+            }
+            self.steiner_points.push((coords, self.vertex_index_counter));
+            self.vertex_index_counter += 1;
+        }
+    }
+
+    /// Add an open polyline whose segments must appear as edges of the
+    /// triangle output (fold lines, road centerlines, UV seams).  `size` = 2
+    /// or 3; `vertices` is flat.  Constraints never change winding or the
+    /// inside/outside classification: parts outside the filled region are
+    /// dropped, and crossings with the boundary or with other constraints
+    /// become new vertices (`TESS_UNDEF` index).  Polyline vertices take
+    /// input vertex indices like `add_points`.  Constraint edges are never
+    /// flipped by CDT or refinement and never merged away by `poly_size > 3`.
+    /// A constraint that cannot be forced into the triangulation (it runs
+    /// back over itself through other crossings, say) makes `tessellate`
+    /// fail with `TessStatus::InvalidInput` rather than be left out.
+    /// Ignored for `BoundaryContours` output.
+    pub fn add_constraint(&mut self, size: usize, vertices: &[Real]) {
+        if self.status != TessStatus::Ok {
+            return;
+        }
+        let size = size.clamp(2, 3);
+        let mut polyline = Vec::with_capacity(vertices.len() / size);
+        for p in vertices.chunks_exact(size) {
+            let coords = [p[0], p[1], if size > 2 { p[2] } else { 0.0 }];
+            if !coords.iter().all(|&c| is_valid_coord(c)) {
+                self.status = TessStatus::InvalidInput;
+                return;
+            }
+            polyline.push((coords, self.vertex_index_counter));
+            self.vertex_index_counter += 1;
+        }
+        self.constraints.push(polyline);
+    }
+
+    fn project_points(&self, points: &[([Real; 3], u32)]) -> Vec<SteinerPoint> {
+        points
+            .iter()
+            .map(|&(c, idx)| ([dot(&c, &self.s_unit), dot(&c, &self.t_unit)], c, idx))
+            .collect()
+    }
+
+    /// Insert the pending `add_points` and `add_constraint` input into the
+    /// triangulated interior.  Sets `TessStatus::InvalidInput` when a
+    /// constraint cannot be forced in.
+    pub(crate) fn insert_steiner_points(&mut self) -> bool {
+        let points = self.project_points(&self.steiner_points);
+        let polylines: Vec<Vec<SteinerPoint>> =
+            self.constraints.iter().map(|c| self.project_points(c)).collect();
+        let legalize = self.process_cdt || self.min_angle > 0.0 || self.max_area > 0.0;
+        let Some(mesh) = self.mesh.as_mut() else {
+            return true;
+        };
+        self.out_dropped_points = points.len() - mesh.insert_points(&points, legalize);
+        // Every constraint is tried so a failure leaves the others in place.
+        let mut inserted = true;
+        for polyline in &polylines {
+            inserted &= mesh.insert_constraint(polyline);
+        }
+        if !inserted {
+            self.status = TessStatus::InvalidInput;
+        }
+        inserted
+    }
+}
@@ -340,3 +340,18 @@ fn edge_flags_on_square_with_hole() {
     let total_boundary: u32 = flags.iter().map(|&f| f as u32).sum();
     assert_eq!(total_boundary, 8, "outer 4 + hole 4 = 8 boundary edges, got {total_boundary}");
 }
+
+#[test]
+fn broken_mesh_fails_the_run_with_its_report() {
+    let mut tess = Tessellator::new();
+    let mut mesh = Mesh::new();
+    let e = mesh.make_edge().unwrap();
+    mesh.edges[e as usize].onext = E_HEAD;
+    tess.mesh = Some(mesh);
+    assert!(!tess.check_mesh_after_event());
+    assert_eq!(tess.get_status(), TessStatus::MeshCheckFailed);
+    assert!(!tess.mesh_report().unwrap().is_ok());
+
+    tess.reset();
+    assert!(tess.mesh_report().is_none());
+}
@@ -0,0 +1,61 @@
+// Copyright 2025 Lars Brubaker
+// License: SGI Free Software License B (MIT-compatible)
+//
+//! Planar texture coordinates (`Tessellator::set_uv_mapping`).
+//!
+//! `tessellate` projects the input onto the plane spanned by `s_unit` and
+//! `t_unit` before sweeping.  With a mapping set, the same projection is
+//! applied to every output vertex and written to `out_uvs`, so a textured
+//! fill of a 3D face needs no second projection pass.  See
+//! [`Tessellator::projection_basis`] for how s and t are picked; pass a
+//! normal to `tessellate` to keep them fixed whatever the contours' winding.
+
+use crate::geom::Real;
+
+use super::Tessellator;
+
+/// How output vertices are turned into texture coordinates.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum UvMapping {
+    /// The projected `(s, t)` coordinates themselves.
+    Projected,
+    /// `(s, t)` scaled so the projected bounding box of the input spans
+    /// `[0, 1]` in both directions.
+    Normalized,
+    /// `(s, t)` mapped through `[a, b, c, d, e, f]`:
+    /// `u = a·s + b·t + c`, `v = d·s + e·t + f`.
+    Affine([Real; 6]),
+}
+
+impl Tessellator {
+    /// Write texture coordinates for every output vertex to `out_uvs` (see
+    /// the module docs), or none with `None` (the default).
+    pub fn set_uv_mapping(&mut self, mapping: Option<UvMapping>) {
+        self.uv_mapping = mapping;
+    }
+
+    /// Two texture coordinates per output vertex, parallel to `vertices()`;
+    /// empty unless a mapping is set.
+    pub fn uvs(&self) -> &[Real] {
+        &self.out_uvs
+    }
+
+    /// Fill `out_uvs` from the (s, t) each output vertex was written with.
+    pub(super) fn output_uvs(&mut self) {
+        self.out_uvs.clear();
+        let Some(mapping) = self.uv_mapping else {
+            return;
+        };
+        let [a, b, c, d, e, f] = match mapping {
+            UvMapping::Projected => [1.0, 0.0, 0.0, 0.0, 1.0, 0.0],
+            UvMapping::Normalized => {
+                let scale = |lo: Real, hi: Real| if hi > lo { 1.0 / (hi - lo) } else { 0.0 };
+                let (ks, kt) = (scale(self.bmin[0], self.bmax[0]), scale(self.bmin[1], self.bmax[1]));
+                [ks, 0.0, -self.bmin[0] * ks, 0.0, kt, -self.bmin[1] * kt]
+            }
+            UvMapping::Affine(m) => m,
+        };
+        let uvs = self.out_projected.iter().flat_map(|&[s, t]| [a * s + b * t + c, d * s + e * t + f]);
+        self.out_uvs.extend(uvs);
+    }
+}
@@ -0,0 +1,56 @@
+// Copyright 2025 Lars Brubaker
+// Voronoi output for `TessOption::Voronoi`.
+
+use super::{Tessellator, TESS_UNDEF};
+use crate::geom::Real;
+
+/// One Voronoi cell: the corners `voronoi_indices()[start..start + count]`
+/// (indices into `voronoi_vertices()`), counter-clockwise in the projection
+/// plane like the output triangles.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct VoronoiCell {
+    /// Output vertex (index into `vertices()`) the cell belongs to.  For
+    /// `ElementType::ConvexPartition`, where a vertex is written once per
+    /// piece it bounds, the first of those.
+    pub site: u32,
+    pub start: usize,
+    pub count: usize,
+}
+
+impl Tessellator {
+    /// Copy the cells `mesh.voronoi_cells_with` left in `voronoi_cells` to
+    /// the output, keeping the output buffers.
+    pub(crate) fn output_voronoi(&mut self, vertex_size: usize) {
+        let mesh = self.mesh.as_ref().unwrap();
+        let cells = &self.voronoi_cells;
+        self.out_voronoi_vertices.clear();
+        self.out_voronoi_vertices.extend(cells.points.iter().flat_map(|p| p[..vertex_size].iter().copied()));
+        for &(site, start, count) in &cells.cells {
+            let n = mesh.verts[site as usize].n;
+            if n == TESS_UNDEF {
+                continue;
+            }
+            let begin = self.out_voronoi_indices.len();
+            self.out_voronoi_indices.extend_from_slice(&cells.indices[start..start + count]);
+            self.out_voronoi_cells.push(VoronoiCell { site: n, start: begin, count });
+        }
+    }
+
+    /// Voronoi cells of the output vertices, clipped to the filled area,
+    /// when `TessOption::Voronoi` is set.  Boundary and constraint edges cut
+    /// the cells; a vertex where separate parts of the fill touch gets one
+    /// cell per part.  Empty for `ElementType::BoundaryContours`.
+    pub fn voronoi_cells(&self) -> &[VoronoiCell] {
+        &self.out_voronoi_cells
+    }
+
+    /// Cell corners, `vertex_size` components each.
+    pub fn voronoi_vertices(&self) -> &[Real] {
+        &self.out_voronoi_vertices
+    }
+
+    /// Corner indices of all cells, concatenated.
+    pub fn voronoi_indices(&self) -> &[u32] {
+        &self.out_voronoi_indices
+    }
+}
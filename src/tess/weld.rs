@@ -0,0 +1,145 @@
+// Copyright 2025 Lars Brubaker
+// License: SGI Free Software License B (MIT-compatible)
+//
+//! Tolerance-based welding (`Tessellator::set_weld_epsilon`).
+//!
+//! The sweep only merges vertices that are exactly equal, so points a
+//! hair apart (CAD exports, hinted glyphs) turn into slivers and spurious
+//! intersections.  Before the sweep, vertices within the epsilon of each
+//! other are snapped onto one representative, and an edge passing within
+//! the epsilon of a vertex is split at exactly that vertex's position; the
+//! sweep's exact coalescing then does the actual merge.  During the sweep,
+//! an intersection landing within the epsilon of the event vertex or of an
+//! edge origin is snapped onto it instead of becoming a new vertex.
+//! Distances are measured in the projection plane.
+
+use crate::geom::Real;
+use crate::mesh::{VertIdx, E_HEAD, V_HEAD};
+
+use super::Tessellator;
+
+impl Tessellator {
+    /// Weld vertices closer than `epsilon` together, and onto edges passing
+    /// closer than `epsilon`.  `0` (the default) keeps exact merging only.
+    pub fn set_weld_epsilon(&mut self, epsilon: Real) {
+        self.weld_epsilon = epsilon.max(0.0);
+    }
+
+    /// Number of epsilon merges (vertex–vertex, vertex–edge and snapped
+    /// intersections) in the last `tessellate` call.  Exact coincidences
+    /// are merged as before and not counted.
+    pub fn weld_count(&self) -> usize {
+        self.out_weld_count
+    }
+
+    /// Snap the contour vertices before the sweep (see module docs).
+    pub(crate) fn weld_vertices(&mut self) -> bool {
+        let eps = self.weld_epsilon;
+        let eps2 = eps * eps;
+        let mesh = self.mesh.as_mut().unwrap();
+        let st = |v: VertIdx| [mesh.verts[v as usize].s, mesh.verts[v as usize].t];
+        let near = |a: [Real; 2], b: [Real; 2]| {
+            (a[0] - b[0]) * (a[0] - b[0]) + (a[1] - b[1]) * (a[1] - b[1]) <= eps2
+        };
+
+        let mut order: Vec<VertIdx> = Vec::new();
+        let mut v = mesh.verts[V_HEAD as usize].next;
+        while v != V_HEAD {
+            order.push(v);
+            v = mesh.verts[v as usize].next;
+        }
+        order.sort_by(|&a, &b| st(a)[0].total_cmp(&st(b)[0]));
+
+        // Vertex–vertex: each vertex joins the first representative within
+        // reach, so clusters never chain further than `eps`.
+        let mut reps: Vec<VertIdx> = Vec::new();
+        let mut snaps: Vec<(VertIdx, VertIdx)> = Vec::new();
+        for &v in &order {
+            let p = st(v);
+            let lo = reps.partition_point(|&r| st(r)[0] < p[0] - eps);
+            match reps[lo..].iter().find(|&&r| near(st(r), p)) {
+                Some(&r) if st(r) != p => snaps.push((v, r)),
+                Some(_) => {}
+                None => reps.push(v),
+            }
+        }
+        for &(v, r) in &snaps {
+            let src = mesh.verts[r as usize].clone();
+            let vert = &mut mesh.verts[v as usize];
+            vert.s = src.s;
+            vert.t = src.t;
+            vert.coords = src.coords;
+        }
+        let mut merges = snaps.len();
+
+        // Vertex–edge: split each edge at the representatives it passes by.
+        let st = |v: VertIdx| [mesh.verts[v as usize].s, mesh.verts[v as usize].t];
+        let mut cuts: Vec<(u32, Vec<(Real, VertIdx)>)> = Vec::new();
+        let mut e = mesh.edges[E_HEAD as usize].next;
+        while e != E_HEAD {
+            let (a, b) = (st(mesh.edges[e as usize].org), st(mesh.dst(e)));
+            let len2 = (b[0] - a[0]) * (b[0] - a[0]) + (b[1] - a[1]) * (b[1] - a[1]);
+            let lo = reps.partition_point(|&r| st(r)[0] < a[0].min(b[0]) - eps);
+            let mut hits = Vec::new();
+            for &r in &reps[lo..] {
+                let p = st(r);
+                if p[0] > a[0].max(b[0]) + eps {
+                    break;
+                }
+                if len2 == 0.0 || near(p, a) || near(p, b) {
+                    continue;
+                }
+                let u = ((p[0] - a[0]) * (b[0] - a[0]) + (p[1] - a[1]) * (b[1] - a[1])) / len2;
+                let foot = [a[0] + (b[0] - a[0]) * u, a[1] + (b[1] - a[1]) * u];
+                if u > 0.0 && u < 1.0 && near(p, foot) {
+                    hits.push((u, r));
+                }
+            }
+            if !hits.is_empty() {
+                hits.sort_by(|x, y| x.0.total_cmp(&y.0));
+                cuts.push((e, hits));
+            }
+            e = mesh.edges[e as usize].next;
+        }
+        for (e, hits) in cuts {
+            let mut e = e;
+            for (_, r) in hits {
+                let Some(e_new) = mesh.split_edge(e) else {
+                    return false;
+                };
+                let w = mesh.edges[e_new as usize].org;
+                let src = mesh.verts[r as usize].clone();
+                let vert = &mut mesh.verts[w as usize];
+                vert.s = src.s;
+                vert.t = src.t;
+                vert.coords = src.coords;
+                vert.idx = src.idx;
+                e = e_new;
+                merges += 1;
+            }
+        }
+        self.out_weld_count += merges;
+        true
+    }
+
+    /// Snap a sweep intersection onto the nearest of `targets` within the
+    /// weld epsilon.
+    pub(crate) fn weld_intersection(&mut self, s: Real, t: Real, targets: [(Real, Real); 3]) -> (Real, Real) {
+        if self.weld_epsilon <= 0.0 {
+            return (s, t);
+        }
+        match nearest_within(s, t, &targets, self.weld_epsilon) {
+            Some(p) if p != (s, t) => {
+                self.out_weld_count += 1;
+                p
+            }
+            _ => (s, t),
+        }
+    }
+}
+
+/// The point of `targets` nearest to `(s, t)`, if one is within `reach`.
+pub(super) fn nearest_within(s: Real, t: Real, targets: &[(Real, Real)], reach: Real) -> Option<(Real, Real)> {
+    let d2 = |p: &(Real, Real)| (p.0 - s) * (p.0 - s) + (p.1 - t) * (p.1 - t);
+    targets.iter().filter(|p| d2(p) <= reach * reach).min_by(|a, b| d2(a).total_cmp(&d2(b))).copied()
+}
@@ -0,0 +1,129 @@
+// Copyright 2025 Lars Brubaker
+// License: SGI Free Software License B (MIT-compatible)
+//
+//! Point winding queries against the pending contours
+//! (`Tessellator::winding_number`), without tessellating.
+//!
+//! The point and contours are projected exactly as `tessellate` would
+//! project them (same normal, same mirroring of t for clockwise input), and
+//! the winding number is counted along a ray from the point towards +t.
+//! Degenerate points follow the sweep's event order: a point on an edge or
+//! a vertex is treated as lying just past it in sweep order (larger s, then
+//! larger t), so it gets the winding of the face the sweep reaches right
+//! after passing it.  The filled set is thus half-open: with 2D input, a
+//! filled axis-aligned square contains its left and bottom edges (apart
+//! from the top-left and bottom-right corners) but not its right and top
+//! edges.  Welding and snapping are not applied.
+
+use crate::geom::Real;
+use crate::mesh::{E_HEAD, INVALID};
+use crate::predicates::orient2d;
+
+use super::geometry::{check_orientation_sign, compute_normal, dot, projection_axes};
+use super::{Tessellator, WindingRule};
+
+impl WindingRule {
+    /// Whether a region with winding number `winding` is filled under this
+    /// rule.
+    pub fn is_inside(self, winding: i32) -> bool {
+        match self {
+            WindingRule::Odd => winding & 1 != 0,
+            WindingRule::NonZero => winding != 0,
+            WindingRule::Positive => winding > 0,
+            WindingRule::Negative => winding < 0,
+            WindingRule::AbsGeqTwo => winding >= 2 || winding <= -2,
+        }
+    }
+}
+
+impl Tessellator {
+    /// The (s, t) projection `tessellate` would use for the pending
+    /// contours with this `normal`.
+    fn query_projection(&self, normal: Option<[Real; 3]>) -> ([Real; 3], [Real; 3]) {
+        let mesh = self.mesh.as_ref().unwrap();
+        let mut norm = normal.unwrap_or([0.0; 3]);
+        let computed = norm == [0.0; 3];
+        if computed {
+            compute_normal(mesh, &mut norm);
+        }
+        let (su, mut tu) = projection_axes(&norm, self.orthonormal_projection);
+        if computed && check_orientation_sign(mesh, &su, &tu) < 0.0 {
+            tu = [-tu[0], -tu[1], -tu[2]];
+        }
+        (su, tu)
+    }
+
+    /// Winding number of `point` with respect to the contours added since
+    /// the last `tessellate`, as the sweep would compute it (see the module
+    /// docs for points on edges and vertices).  `normal` is the one that
+    /// would be passed to `tessellate`.  Test it with
+    /// [`WindingRule::is_inside`] for hit testing.
+    pub fn winding_number(&self, point: [Real; 3], normal: Option<[Real; 3]>) -> i32 {
+        let mut out = Vec::with_capacity(1);
+        self.winding_numbers_into(3, &point, normal, &mut out);
+        out[0]
+    }
+
+    /// `winding_number` for many points at once: `points` is flat with
+    /// `size` (2 or 3) coordinates per point.
+    pub fn winding_numbers(&self, size: usize, points: &[Real], normal: Option<[Real; 3]>) -> Vec<i32> {
+        let mut out = Vec::new();
+        self.winding_numbers_into(size, points, normal, &mut out);
+        out
+    }
+
+    fn winding_numbers_into(&self, size: usize, points: &[Real], normal: Option<[Real; 3]>, out: &mut Vec<i32>) {
+        let size = size.clamp(2, 3);
+        let count = points.len() / size;
+        let Some(mesh) = self.mesh.as_ref() else {
+            out.resize(count, 0);
+            return;
+        };
+        let (su, tu) = self.query_projection(normal);
+        let project = |c: &[Real; 3]| [dot(c, &su), dot(c, &tu)];
+
+        // Every contour edge as (left end, right end, winding of the
+        // half-edge pointing right), vertical edges left out.
+        let mut edges: Vec<([Real; 2], [Real; 2], i32)> = Vec::new();
+        let mut e = mesh.edges[E_HEAD as usize].next;
+        while e != E_HEAD && e != INVALID {
+            let (a, b) = (
+                project(&mesh.verts[mesh.edges[e as usize].org as usize].coords),
+                project(&mesh.verts[mesh.dst(e) as usize].coords),
+            );
+            let w = mesh.edges[e as usize].winding;
+            if a[0] < b[0] {
+                edges.push((a, b, w));
+            } else if a[0] > b[0] {
+                edges.push((b, a, -w));
+            }
+            e = mesh.edges[e as usize].next;
+        }
+        edges.sort_by(|x, y| x.0[0].total_cmp(&y.0[0]));
+
+        let exact = self.exact_predicates;
+        for p in points.chunks_exact(size) {
+            let q = project(&[p[0], p[1], if size > 2 { p[2] } else { 0.0 }]);
+            let mut winding = 0;
+            for &(l, r, w) in edges.iter().take_while(|edge| edge.0[0] <= q[0]) {
+                if q[0] >= r[0] {
+                    continue;
+                }
+                let side = if exact {
+                    orient2d(l, r, q)
+                } else {
+                    (r[0] - l[0]) * (q[1] - l[1]) - (r[1] - l[1]) * (q[0] - l[0])
+                };
+                // On the edge: the point just past it in sweep order is
+                // below the edge iff the edge rises.
+                let below = side < 0.0 || (side == 0.0 && r[1] > l[1]);
+                if below {
+                    // Crossing a rightward half-edge upwards goes from its
+                    // right face to its left face.
+                    winding -= w;
+                }
+            }
+            out.push(winding);
+        }
+    }
+}
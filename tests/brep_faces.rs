@@ -0,0 +1,164 @@
+// Copyright 2025 Lars Brubaker
+// Tests for batch tessellation of B-rep faces (tessellate_faces).
+
+use std::collections::HashMap;
+
+use tess2_rust::{BrepFace, FaceError, TessStatus, Tessellator, WindingRule};
+
+const CUBE: [f64; 24] = [
+    0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0, //
+    0.0, 0.0, 1.0, 1.0, 0.0, 1.0, 1.0, 1.0, 1.0, 0.0, 1.0, 1.0,
+];
+
+/// The six faces of the unit cube, counter-clockwise seen from outside.
+fn cube_faces() -> Vec<BrepFace> {
+    let quads = [[0, 3, 2, 1], [4, 5, 6, 7], [0, 1, 5, 4], [2, 3, 7, 6], [1, 2, 6, 5], [0, 4, 7, 3]];
+    quads.iter().map(|q| BrepFace { loops: vec![q.to_vec()], normal: None }).collect()
+}
+
+fn point(vertices: &[f64], v: u32) -> [f64; 3] {
+    let v = v as usize * 3;
+    [vertices[v], vertices[v + 1], vertices[v + 2]]
+}
+
+fn triangle_normal(vertices: &[f64], t: &[u32]) -> [f64; 3] {
+    let (a, b, c) = (point(vertices, t[0]), point(vertices, t[1]), point(vertices, t[2]));
+    let (u, v) = ([b[0] - a[0], b[1] - a[1], b[2] - a[2]], [c[0] - a[0], c[1] - a[1], c[2] - a[2]]);
+    [u[1] * v[2] - u[2] * v[1], u[2] * v[0] - u[0] * v[2], u[0] * v[1] - u[1] * v[0]]
+}
+
+#[test]
+fn cube_faces_share_vertices_and_face_outward() {
+    let mut tess = Tessellator::new();
+    let mesh = tess.tessellate_faces(&CUBE, &cube_faces(), WindingRule::Odd).unwrap();
+    assert_eq!(mesh.vertices, CUBE.to_vec());
+    assert_eq!(mesh.triangles.len(), 36);
+    assert!(mesh.triangles.iter().all(|&v| v < 8));
+    let mut per_face = [0; 6];
+    for &f in &mesh.triangle_faces {
+        per_face[f as usize] += 1;
+    }
+    assert_eq!(per_face, [2; 6]);
+
+    // Every triangle points away from the cube's centre.
+    for t in mesh.triangles.chunks_exact(3) {
+        let n = triangle_normal(&mesh.vertices, t);
+        let a = point(&mesh.vertices, t[0]);
+        let out = n[0] * (a[0] - 0.5) + n[1] * (a[1] - 0.5) + n[2] * (a[2] - 0.5);
+        assert!(out > 0.0, "triangle {t:?} faces inwards");
+    }
+
+    assert_eq!(unmatched_edges(&mesh.triangles), 0);
+}
+
+/// Edges not matched by their reverse exactly once; zero for a
+/// watertight mesh.
+fn unmatched_edges(triangles: &[u32]) -> usize {
+    let mut edges: HashMap<(u32, u32), i32> = HashMap::new();
+    for t in triangles.chunks_exact(3) {
+        for i in 0..3 {
+            let (a, b) = (t[i], t[(i + 1) % 3]);
+            *edges.entry((a.min(b), a.max(b))).or_default() += if a < b { 1 } else { -1 };
+        }
+    }
+    edges.values().filter(|&&w| w != 0).count()
+}
+
+#[test]
+fn refined_faces_stay_watertight() {
+    let mut tess = Tessellator::new();
+    tess.set_max_area(0.05);
+    let mesh = tess.tessellate_faces(&CUBE, &cube_faces(), WindingRule::Odd).unwrap();
+    assert!(mesh.vertices.len() > CUBE.len());
+    assert_eq!(unmatched_edges(&mesh.triangles), 0);
+
+    // Every triangle keeps to the bound, and the faces still cover the cube.
+    let mut total = 0.0;
+    for t in mesh.triangles.chunks_exact(3) {
+        let n = triangle_normal(&mesh.vertices, t);
+        let area = 0.5 * (n[0] * n[0] + n[1] * n[1] + n[2] * n[2]).sqrt();
+        assert!(area <= 0.05 + 1e-12);
+        total += area;
+    }
+    assert!((total - 6.0).abs() < 1e-9);
+}
+
+#[test]
+fn explicit_normals_orient_each_face() {
+    // A square in z = 0, once seen from above and once from below.
+    let positions = [0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 2.0, 2.0, 0.0, 0.0, 2.0, 0.0];
+    let faces = [
+        BrepFace { loops: vec![vec![0, 1, 2, 3]], normal: Some([0.0, 0.0, 1.0]) },
+        BrepFace { loops: vec![vec![0, 1, 2, 3]], normal: Some([0.0, 0.0, -1.0]) },
+    ];
+    let mesh = Tessellator::new().tessellate_faces(&positions, &faces, WindingRule::Odd).unwrap();
+    assert_eq!(mesh.triangle_faces, vec![0, 0, 1, 1]);
+    for (t, &f) in mesh.triangles.chunks_exact(3).zip(&mesh.triangle_faces) {
+        let z = triangle_normal(&mesh.vertices, t)[2];
+        assert_eq!(z > 0.0, f == 0);
+    }
+}
+
+#[test]
+fn faces_with_holes_and_new_vertices() {
+    // A square frame in the x-z plane with a square hole, then two
+    // crossing segments whose intersection becomes a new vertex.
+    let positions = [
+        0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 4.0, 0.0, 4.0, 0.0, 0.0, 4.0, //
+        1.0, 0.0, 1.0, 3.0, 0.0, 1.0, 3.0, 0.0, 3.0, 1.0, 0.0, 3.0, //
+        0.0, 1.0, 0.0, 2.0, 1.0, 0.0, 0.0, 1.0, 2.0, 2.0, 1.0, 2.0,
+    ];
+    let faces = [
+        BrepFace { loops: vec![vec![0, 1, 2, 3], vec![4, 7, 6, 5]], normal: None },
+        BrepFace { loops: vec![vec![8, 9, 10, 11]], normal: Some([0.0, 1.0, 0.0]) },
+    ];
+    let mesh = Tessellator::new().tessellate_faces(&positions, &faces, WindingRule::Positive).unwrap();
+    let frame: Vec<u32> = mesh
+        .triangles
+        .chunks_exact(3)
+        .zip(&mesh.triangle_faces)
+        .filter(|(_, &f)| f == 0)
+        .flat_map(|(t, _)| t.to_vec())
+        .collect();
+    assert_eq!(frame.len(), 8 * 3);
+    assert!(frame.iter().all(|&v| v < 8));
+
+    // The bow tie adds its crossing at (1, 1, 1) after the input vertices.
+    assert_eq!(mesh.vertices.len(), positions.len() + 3);
+    assert_eq!(point(&mesh.vertices, 12), [1.0, 1.0, 1.0]);
+    assert!(mesh.triangle_faces.iter().filter(|&&f| f == 1).count() >= 1);
+    assert!(mesh.triangles.iter().all(|&v| v < 13));
+}
+
+#[test]
+fn refined_hole_touching_its_outer_loop_stays_watertight() {
+    // A flat pillow: a square whose triangular hole touches corner 0, the
+    // triangle filling that hole, and the square's back face.  The hole
+    // hugs the bottom edge, so the square splits the hole's edges at 0.
+    let positions = [
+        0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 4.0, 4.0, 0.0, 0.0, 4.0, 0.0, //
+        2.0, 0.2, 0.0, 1.0, 3.0, 0.0,
+    ];
+    let faces = [
+        BrepFace { loops: vec![vec![0, 1, 2, 3], vec![0, 5, 4]], normal: None },
+        BrepFace { loops: vec![vec![0, 4, 5]], normal: None },
+        BrepFace { loops: vec![vec![0, 3, 2, 1]], normal: None },
+    ];
+    let mut tess = Tessellator::new();
+    tess.set_max_area(0.05);
+    let mesh = tess.tessellate_faces(&positions, &faces, WindingRule::Odd).unwrap();
+    assert!(mesh.vertices.len() > positions.len());
+    assert_eq!(unmatched_edges(&mesh.triangles), 0);
+}
+
+#[test]
+fn bad_faces_report_their_index() {
+    let mut faces = cube_faces();
+    faces[3].loops[0][2] = 8;
+    let mut tess = Tessellator::new();
+    let err = tess.tessellate_faces(&CUBE, &faces, WindingRule::Odd).unwrap_err();
+    assert_eq!(err, FaceError { face: 3, status: TessStatus::InvalidInput });
+
+    // The tessellator is ready for the next batch.
+    assert!(tess.tessellate_faces(&CUBE, &cube_faces(), WindingRule::Odd).is_ok());
+}
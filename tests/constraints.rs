@@ -0,0 +1,168 @@
+// Copyright 2025 Lars Brubaker
+// Tests for open constraint polylines (add_constraint).
+
+mod helpers;
+
+use tess2_rust::{ElementType, TessOption, TessStatus, Tessellator, WindingRule};
+
+const SQUARE: [f64; 8] = [0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0];
+
+fn orient(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> f64 {
+    (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+}
+
+fn within(a: [f64; 2], b: [f64; 2], p: [f64; 2]) -> bool {
+    let t = ((p[0] - a[0]) * (b[0] - a[0]) + (p[1] - a[1]) * (b[1] - a[1]))
+        / ((b[0] - a[0]).powi(2) + (b[1] - a[1]).powi(2));
+    (-1e-9..=1.0 + 1e-9).contains(&t)
+}
+
+/// Unique output edges of an `EdgeList` tessellation.
+fn edges(tess: &Tessellator) -> Vec<[[f64; 2]; 2]> {
+    let v = tess.vertices();
+    let p = |i: u32| [v[i as usize * 2], v[i as usize * 2 + 1]];
+    tess.elements().chunks(2).map(|e| [p(e[0]), p(e[1])]).collect()
+}
+
+/// Length of output edges lying on segment a-b, and the number of output
+/// edges crossing it.
+fn coverage(tess: &Tessellator, a: [f64; 2], b: [f64; 2]) -> (f64, usize) {
+    let mut covered = 0.0;
+    let mut crossing = 0;
+    for [p, q] in edges(tess) {
+        let (d1, d2) = (orient(a, b, p), orient(a, b, q));
+        if d1.abs() < 1e-9 && d2.abs() < 1e-9 && within(a, b, p) && within(a, b, q) {
+            covered += (q[0] - p[0]).hypot(q[1] - p[1]);
+        } else if d1 * d2 < 0.0 && orient(p, q, a) * orient(p, q, b) < 0.0 {
+            crossing += 1;
+        }
+    }
+    (covered, crossing)
+}
+
+fn grid_points() -> Vec<f64> {
+    let mut points = Vec::new();
+    for i in 1..10 {
+        for j in 1..10 {
+            points.push(i as f64 + 0.03 * j as f64);
+            points.push(j as f64 + 0.02 * i as f64);
+        }
+    }
+    points
+}
+
+fn tessellate(tess: &mut Tessellator, element_type: ElementType) {
+    assert!(tess.tessellate(WindingRule::Odd, element_type, 3, 2, None));
+}
+
+#[test]
+fn constraint_through_point_grid_is_respected() {
+    let (a, b) = ([0.5, 4.5], [9.5, 5.5]);
+    let mut tess = Tessellator::new();
+    tess.set_option(TessOption::ConstrainedDelaunayTriangulation, true);
+    tess.add_contour(2, &SQUARE);
+    tess.add_points(2, &grid_points());
+    tess.add_constraint(2, &[a[0], a[1], b[0], b[1]]);
+    tessellate(&mut tess, ElementType::EdgeList);
+    let (covered, crossing) = coverage(&tess, a, b);
+    assert_eq!(crossing, 0);
+    assert!((covered - (9.0f64).hypot(1.0)).abs() < 1e-9, "covered {}", covered);
+}
+
+#[test]
+fn constraint_is_clipped_to_the_fill() {
+    let mut tess = Tessellator::new();
+    tess.add_contour(2, &SQUARE);
+    tess.add_constraint(2, &[-5.0, 5.0, 15.0, 5.0]);
+    tessellate(&mut tess, ElementType::EdgeList);
+    let (covered, crossing) = coverage(&tess, [-5.0, 5.0], [15.0, 5.0]);
+    assert_eq!(crossing, 0);
+    assert!((covered - 10.0).abs() < 1e-9);
+    // Two cut points on the boundary; the outside endpoints are dropped.
+    assert_eq!(tess.vertex_count(), 6);
+    let boundary: f64 = edges(&tess)
+        .iter()
+        .zip(tess.edge_flags())
+        .filter(|(_, &f)| f == 1)
+        .map(|([p, q], _)| (q[0] - p[0]).hypot(q[1] - p[1]))
+        .sum();
+    assert!((boundary - 40.0).abs() < 1e-9);
+}
+
+#[test]
+fn constraint_in_a_hole_changes_nothing() {
+    let hole = [3.0, 3.0, 3.0, 7.0, 7.0, 7.0, 7.0, 3.0];
+    let mut plain = Tessellator::new();
+    plain.add_contour(2, &SQUARE);
+    plain.add_contour(2, &hole);
+    tessellate(&mut plain, ElementType::Polygons);
+
+    let mut tess = Tessellator::new();
+    tess.add_contour(2, &SQUARE);
+    tess.add_contour(2, &hole);
+    tess.add_constraint(2, &[4.0, 4.0, 6.0, 6.0]);
+    tessellate(&mut tess, ElementType::Polygons);
+    assert_eq!(tess.vertex_count(), plain.vertex_count());
+    assert_eq!(tess.element_count(), plain.element_count());
+    assert!((helpers::total_tessellation_area(&tess) - 84.0).abs() < 1e-9);
+}
+
+#[test]
+fn crossing_constraints_share_a_vertex() {
+    let mut tess = Tessellator::new();
+    tess.add_contour(2, &SQUARE);
+    tess.add_constraint(2, &[1.0, 1.0, 9.0, 9.0]);
+    tess.add_constraint(2, &[1.0, 9.0, 9.0, 1.0]);
+    tessellate(&mut tess, ElementType::EdgeList);
+    assert!(tess.vertices().chunks(2).any(|v| v == [5.0, 5.0]));
+    for (a, b) in [([1.0, 1.0], [9.0, 9.0]), ([1.0, 9.0], [9.0, 1.0])] {
+        let (covered, crossing) = coverage(&tess, a, b);
+        assert_eq!(crossing, 0);
+        assert!((covered - 8.0 * 2f64.sqrt()).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn constraint_that_cannot_be_inserted_fails_the_run() {
+    // The second polyline crosses the hole's corner and the first
+    // constraint, then runs back over itself through the crossing, which
+    // lies off its line once rounded.
+    let mut tess = Tessellator::new();
+    tess.add_contour(2, &SQUARE);
+    tess.add_contour(2, &[3.0, 3.0, 3.0, 7.0, 7.0, 7.0, 7.0, 3.0]);
+    tess.add_constraint(2, &[4.0, 0.0, 2.0, 6.0, 2.0, 1.0]);
+    tess.add_constraint(2, &[10.0, 8.0, 1.0, 5.0, 4.0, 6.0]);
+    assert!(!tess.tessellate(WindingRule::Odd, ElementType::Polygons, 3, 2, None));
+    assert_eq!(tess.get_status(), TessStatus::InvalidInput);
+
+    // Each polyline on its own goes in.
+    for constraint in [vec![4.0, 0.0, 2.0, 6.0, 2.0, 1.0], vec![10.0, 8.0, 1.0, 5.0, 4.0, 6.0]] {
+        let mut tess = Tessellator::new();
+        tess.add_contour(2, &SQUARE);
+        tess.add_contour(2, &[3.0, 3.0, 3.0, 7.0, 7.0, 7.0, 7.0, 3.0]);
+        tess.add_constraint(2, &constraint);
+        tessellate(&mut tess, ElementType::Polygons);
+    }
+}
+
+#[test]
+fn constraints_survive_refinement_and_polygon_merging() {
+    let polyline = [2.0, 2.0, 5.0, 8.0, 8.0, 2.0];
+    let mut tess = Tessellator::new();
+    tess.set_min_angle(25.0);
+    tess.add_contour(2, &SQUARE);
+    tess.add_constraint(2, &polyline);
+    tessellate(&mut tess, ElementType::EdgeList);
+    for seg in [[[2.0, 2.0], [5.0, 8.0]], [[5.0, 8.0], [8.0, 2.0]]] {
+        let (covered, crossing) = coverage(&tess, seg[0], seg[1]);
+        assert_eq!(crossing, 0);
+        assert!((covered - 3f64.hypot(6.0)).abs() < 1e-9);
+    }
+
+    // A diagonal constraint keeps the square from merging into one quad.
+    let mut tess = Tessellator::new();
+    tess.add_contour(2, &SQUARE);
+    tess.add_constraint(2, &[0.0, 0.0, 10.0, 10.0]);
+    assert!(tess.tessellate(WindingRule::Odd, ElementType::Polygons, 4, 2, None));
+    assert_eq!(tess.element_count(), 2);
+}
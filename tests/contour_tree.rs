@@ -0,0 +1,170 @@
+// Copyright 2025 Lars Brubaker
+// Tests for the BoundaryContours ring nesting (contour tree).
+
+mod helpers;
+
+use tess2_rust::{ElementType, Orientation, TessOption, Tessellator, WindingRule};
+
+fn square(x0: f64, y0: f64, size: f64) -> Vec<f64> {
+    vec![x0, y0, x0 + size, y0, x0 + size, y0 + size, x0, y0 + size]
+}
+
+fn reversed(contour: &[f64]) -> Vec<f64> {
+    contour.chunks(2).rev().flatten().copied().collect()
+}
+
+fn boundary(contours: &[Vec<f64>], rule: WindingRule) -> Tessellator {
+    boundary_oriented(contours, rule, None, None)
+}
+
+fn boundary_oriented(
+    contours: &[Vec<f64>],
+    rule: WindingRule,
+    orientation: Option<Orientation>,
+    normal: Option<[f64; 3]>,
+) -> Tessellator {
+    let mut tess = Tessellator::new();
+    tess.set_contour_orientation(orientation);
+    for c in contours {
+        tess.add_contour(2, c);
+    }
+    assert!(tess.tessellate(rule, ElementType::BoundaryContours, 0, 2, normal));
+    tess
+}
+
+/// Signed X/Y area of output contour `i`.
+fn contour_area(tess: &Tessellator, i: usize) -> f64 {
+    let start = tess.elements()[i * 2] as usize;
+    let count = tess.elements()[i * 2 + 1] as usize;
+    helpers::polygon_signed_area(&tess.vertices()[start * 2..(start + count) * 2])
+}
+
+#[test]
+fn boundary_contours_emit_rings_not_triangles() {
+    let tess = boundary(&[square(0.0, 0.0, 10.0), reversed(&square(3.0, 3.0, 4.0))], WindingRule::Positive);
+    assert_eq!(tess.element_count(), 2);
+    assert_eq!(tess.vertex_count(), 8);
+}
+
+#[test]
+fn square_with_hole_forms_one_polygon() {
+    let tess = boundary(&[square(0.0, 0.0, 10.0), reversed(&square(3.0, 3.0, 4.0))], WindingRule::Positive);
+    let tree = tess.contour_tree();
+    assert_eq!(tree.len(), 2);
+
+    let outer = tree.iter().position(|n| !n.is_hole).unwrap();
+    let hole = 1 - outer;
+    assert_eq!(tree[outer].parent, None);
+    assert_eq!(tree[outer].depth, 0);
+    assert_eq!(tree[outer].children, vec![hole]);
+    assert_eq!(tree[outer].orientation, Orientation::CounterClockwise);
+    assert!((tree[outer].area - 100.0).abs() < 1e-9);
+
+    assert_eq!(tree[hole].parent, Some(outer));
+    assert_eq!(tree[hole].depth, 1);
+    assert!(tree[hole].is_hole);
+    assert_eq!(tree[hole].orientation, Orientation::Clockwise);
+    assert!((tree[hole].area - 16.0).abs() < 1e-9);
+
+    let polys = tess.polygons_with_holes();
+    assert_eq!(polys.len(), 1);
+    assert_eq!(polys[0].outer, outer);
+    assert_eq!(polys[0].holes, vec![hole]);
+}
+
+#[test]
+fn island_inside_hole_starts_a_new_polygon() {
+    let contours = [square(0.0, 0.0, 30.0), square(5.0, 5.0, 20.0), square(10.0, 10.0, 10.0)];
+    let tess = boundary(&contours, WindingRule::Odd);
+    let tree = tess.contour_tree();
+    assert_eq!(tree.len(), 3);
+
+    let mut depths: Vec<u32> = tree.iter().map(|n| n.depth).collect();
+    depths.sort();
+    assert_eq!(depths, vec![0, 1, 2]);
+    for node in tree {
+        assert_eq!(node.is_hole, node.depth % 2 == 1);
+    }
+
+    let polys = tess.polygons_with_holes();
+    assert_eq!(polys.len(), 2);
+    let holes: usize = polys.iter().map(|p| p.holes.len()).sum();
+    assert_eq!(holes, 1);
+}
+
+#[test]
+fn disjoint_squares_are_separate_roots() {
+    let tess = boundary(&[square(0.0, 0.0, 1.0), square(5.0, 0.0, 1.0)], WindingRule::Positive);
+    let tree = tess.contour_tree();
+    assert_eq!(tree.len(), 2);
+    assert!(tree.iter().all(|n| n.parent.is_none() && n.depth == 0 && !n.is_hole));
+    assert_eq!(tess.polygons_with_holes().len(), 2);
+}
+
+#[test]
+fn reported_orientation_matches_output_vertex_order() {
+    // Clockwise input makes the orientation check mirror the projection.
+    let contours = [reversed(&square(0.0, 0.0, 10.0)), square(3.0, 3.0, 4.0)];
+    let tess = boundary(&contours, WindingRule::NonZero);
+    assert_eq!(tess.element_count(), 2);
+    for (i, node) in tess.contour_tree().iter().enumerate() {
+        let ccw = contour_area(&tess, i) > 0.0;
+        assert_eq!(node.orientation == Orientation::CounterClockwise, ccw, "contour {}", i);
+    }
+}
+
+#[test]
+fn contour_tree_is_empty_for_triangle_output() {
+    let mut tess = Tessellator::new();
+    tess.set_option(TessOption::ReverseContours, false);
+    tess.add_contour(2, &square(0.0, 0.0, 1.0));
+    assert!(tess.tessellate(WindingRule::Positive, ElementType::Polygons, 3, 2, None));
+    assert!(tess.contour_tree().is_empty());
+}
+
+#[test]
+fn requested_orientation_holds_for_either_input_winding() {
+    let ccw_input = [square(0.0, 0.0, 10.0), reversed(&square(3.0, 3.0, 4.0))];
+    let cw_input = [reversed(&square(0.0, 0.0, 10.0)), square(3.0, 3.0, 4.0)];
+    for input in [&ccw_input, &cw_input] {
+        for outer in [Orientation::CounterClockwise, Orientation::Clockwise] {
+            let tess = boundary_oriented(input, WindingRule::NonZero, Some(outer), None);
+            assert_eq!(tess.element_count(), 2);
+            for (i, node) in tess.contour_tree().iter().enumerate() {
+                let wanted = if node.is_hole { outer.reversed() } else { outer };
+                assert_eq!(node.orientation, wanted);
+                let ccw = contour_area(&tess, i) > 0.0;
+                assert_eq!(ccw, wanted == Orientation::CounterClockwise, "contour {}", i);
+            }
+        }
+    }
+}
+
+#[test]
+fn requested_orientation_is_relative_to_the_normal() {
+    // Seen from -Z, counter-clockwise is clockwise in the X/Y plane.
+    let contours = [square(0.0, 0.0, 10.0), reversed(&square(3.0, 3.0, 4.0))];
+    let tess = boundary_oriented(
+        &contours,
+        WindingRule::NonZero,
+        Some(Orientation::CounterClockwise),
+        Some([0.0, 0.0, -1.0]),
+    );
+    let tree = tess.contour_tree();
+    assert_eq!(tree.len(), 2);
+    for (i, node) in tree.iter().enumerate() {
+        let xy_ccw = contour_area(&tess, i) > 0.0;
+        assert_eq!(xy_ccw, node.is_hole, "contour {}", i);
+    }
+}
+
+#[test]
+fn reordering_keeps_vertex_indices_aligned() {
+    let contours = [reversed(&square(0.0, 0.0, 10.0))];
+    let tess = boundary_oriented(&contours, WindingRule::NonZero, Some(Orientation::CounterClockwise), None);
+    let input = &contours[0];
+    for (i, &idx) in tess.vertex_indices().iter().enumerate() {
+        let idx = idx as usize;
+        assert_eq!(&tess.vertices()[i * 2..i * 2 + 2], &input[idx * 2..idx * 2 + 2]);
+    }
+}
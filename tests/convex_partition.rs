@@ -0,0 +1,136 @@
+// Copyright 2025 Lars Brubaker
+// Tests for ElementType::ConvexPartition.
+
+mod helpers;
+
+use tess2_rust::{ElementType, Tessellator, WindingRule};
+
+fn partition(contours: &[Vec<f64>]) -> (Tessellator, Vec<Vec<[f64; 2]>>) {
+    let mut tess = Tessellator::new();
+    for c in contours {
+        tess.add_contour(2, c);
+    }
+    assert!(tess.tessellate(WindingRule::Odd, ElementType::ConvexPartition, 3, 2, None));
+    let v = tess.vertices();
+    let pieces = tess
+        .elements()
+        .chunks(2)
+        .map(|p| (p[0] as usize..(p[0] + p[1]) as usize).map(|i| [v[i * 2], v[i * 2 + 1]]).collect())
+        .collect();
+    (tess, pieces)
+}
+
+fn greedy_count(contours: &[Vec<f64>]) -> usize {
+    let mut tess = Tessellator::new();
+    for c in contours {
+        tess.add_contour(2, c);
+    }
+    assert!(tess.tessellate(WindingRule::Odd, ElementType::Polygons, 1000, 2, None));
+    tess.element_count()
+}
+
+fn area(piece: &[[f64; 2]]) -> f64 {
+    let flat: Vec<f64> = piece.iter().flat_map(|p| [p[0], p[1]]).collect();
+    helpers::polygon_signed_area(&flat)
+}
+
+fn turn(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> f64 {
+    (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+}
+
+/// Every piece is a counter-clockwise convex polygon and together they
+/// cover `expected`.
+fn assert_convex_cover(pieces: &[Vec<[f64; 2]>], expected: f64) {
+    let mut total = 0.0;
+    for piece in pieces {
+        let n = piece.len();
+        for i in 0..n {
+            let t = turn(piece[i], piece[(i + 1) % n], piece[(i + 2) % n]);
+            assert!(t >= -1e-9, "piece {:?} is not convex", piece);
+        }
+        total += area(piece);
+    }
+    assert!((total - expected).abs() < 1e-9 * expected.max(1.0), "pieces cover {} of {}", total, expected);
+}
+
+fn reflex_count(contour: &[f64]) -> usize {
+    let n = contour.len() / 2;
+    let p = |k: usize| [contour[(k % n) * 2], contour[(k % n) * 2 + 1]];
+    (0..n).filter(|&i| turn(p(i + n - 1), p(i), p(i + 1)) < 0.0).count()
+}
+
+#[test]
+fn convex_input_is_one_piece() {
+    let circle: Vec<f64> = (0..64)
+        .flat_map(|i| {
+            let a = i as f64 / 64.0 * std::f64::consts::TAU;
+            [a.cos() * 10.0, a.sin() * 10.0]
+        })
+        .collect();
+    let (tess, pieces) = partition(&[circle]);
+    assert_eq!(tess.element_count(), 1);
+    assert_eq!(pieces[0].len(), 64);
+    assert!(tess.edge_flags().iter().all(|&f| f == 1));
+}
+
+#[test]
+fn plus_sign_needs_three_pieces() {
+    let plus = vec![
+        1.0, 0.0, 2.0, 0.0, 2.0, 1.0, 3.0, 1.0, 3.0, 2.0, 2.0, 2.0, 2.0, 3.0, 1.0, 3.0, 1.0, 2.0, 0.0, 2.0, 0.0, 1.0,
+        1.0, 1.0,
+    ];
+    let (_, pieces) = partition(&[plus]);
+    assert_eq!(pieces.len(), 3);
+    assert_convex_cover(&pieces, 5.0);
+}
+
+#[test]
+fn hole_keeps_boundary_flags() {
+    let outer = vec![0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0];
+    let hole = vec![3.0, 3.0, 3.0, 7.0, 7.0, 7.0, 7.0, 3.0];
+    let (tess, pieces) = partition(&[outer, hole]);
+    assert_convex_cover(&pieces, 84.0);
+    assert!(pieces.len() <= 4);
+
+    let mut flagged = 0.0;
+    let mut k = 0;
+    for piece in &pieces {
+        for i in 0..piece.len() {
+            if tess.edge_flags()[k] == 1 {
+                let (a, b) = (piece[i], piece[(i + 1) % piece.len()]);
+                flagged += (b[0] - a[0]).hypot(b[1] - a[1]);
+            }
+            k += 1;
+        }
+    }
+    assert!((flagged - 56.0).abs() < 1e-9);
+}
+
+#[test]
+fn random_stars_beat_greedy_merging() {
+    let mut seed = 3u64;
+    let mut rnd = || {
+        seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (seed >> 11) as f64 / (1u64 << 53) as f64
+    };
+    let (mut ours, mut greedy) = (0, 0);
+    for _ in 0..100 {
+        let n = 6 + (rnd() * 30.0) as usize;
+        let star: Vec<f64> = (0..n)
+            .flat_map(|i| {
+                let a = i as f64 / n as f64 * std::f64::consts::TAU;
+                let r = 2.0 + rnd() * 8.0;
+                [r * a.cos(), r * a.sin()]
+            })
+            .collect();
+        let contours = [star];
+        let (tess, pieces) = partition(&contours);
+        assert_convex_cover(&pieces, helpers::polygon_signed_area(&contours[0]));
+        let g = greedy_count(&contours);
+        assert!(tess.element_count() <= g);
+        assert!(tess.element_count() <= 2 * reflex_count(&contours[0]) + 1);
+        ours += tess.element_count();
+        greedy += g;
+    }
+    assert!(ours < greedy, "{} pieces vs {} from greedy merging", ours, greedy);
+}
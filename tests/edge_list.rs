@@ -0,0 +1,98 @@
+// Copyright 2025 Lars Brubaker
+// Tests for the EdgeList (wireframe) element type.
+
+mod helpers;
+
+use std::collections::HashSet;
+
+use tess2_rust::{ElementType, TessOption, Tessellator, WindingRule};
+
+fn polygon_with_hole(tess: &mut Tessellator) {
+    tess.set_option(TessOption::ReverseContours, false);
+    tess.add_contour(2, &[0.0f64, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0]);
+    tess.set_option(TessOption::ReverseContours, true);
+    tess.add_contour(2, &[3.0f64, 3.0, 7.0, 3.0, 7.0, 7.0, 3.0, 7.0]);
+}
+
+/// Unordered edge keys (by output vertex position) for comparing two runs.
+fn edge_keys(verts: &[f64], pairs: impl Iterator<Item = (u32, u32)>) -> HashSet<[i64; 4]> {
+    pairs
+        .map(|(a, b)| {
+            let pa = (verts[a as usize * 2] as i64, verts[a as usize * 2 + 1] as i64);
+            let pb = (verts[b as usize * 2] as i64, verts[b as usize * 2 + 1] as i64);
+            let (p, q) = if pa <= pb { (pa, pb) } else { (pb, pa) };
+            [p.0, p.1, q.0, q.1]
+        })
+        .collect()
+}
+
+#[test]
+fn edge_list_quad_has_four_boundary_edges_and_one_diagonal() {
+    let mut tess = Tessellator::new();
+    tess.add_contour(2, &[0.0f64, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0]);
+    assert!(tess.tessellate(WindingRule::Positive, ElementType::EdgeList, 3, 2, None));
+
+    assert_eq!(tess.element_count(), 5);
+    assert_eq!(tess.elements().len(), 10);
+    assert_eq!(tess.edge_flags().len(), 5);
+    let boundary = tess.edge_flags().iter().filter(|&&f| f == 1).count();
+    assert_eq!(boundary, 4, "quad has four boundary edges");
+    helpers::verify_valid_output(&tess);
+}
+
+#[test]
+fn edge_list_matches_deduplicated_triangle_edges() {
+    let mut tri = Tessellator::new();
+    polygon_with_hole(&mut tri);
+    assert!(tri.tessellate(WindingRule::Positive, ElementType::Polygons, 3, 2, None));
+    let tri_edges = edge_keys(
+        tri.vertices(),
+        tri.elements()
+            .chunks(3)
+            .flat_map(|t| [(t[0], t[1]), (t[1], t[2]), (t[2], t[0])]),
+    );
+    let tri_boundary = tri.edge_flags().iter().filter(|&&f| f == 1).count();
+
+    let mut edges = Tessellator::new();
+    polygon_with_hole(&mut edges);
+    assert!(edges.tessellate(WindingRule::Positive, ElementType::EdgeList, 3, 2, None));
+    let list = edges.elements();
+    let list_edges = edge_keys(edges.vertices(), list.chunks(2).map(|p| (p[0], p[1])));
+
+    assert_eq!(list_edges.len(), edges.element_count(), "edges must be unique");
+    assert_eq!(list_edges, tri_edges);
+    let boundary = edges.edge_flags().iter().filter(|&&f| f == 1).count();
+    assert_eq!(boundary, 8, "outer square + hole have eight boundary edges");
+    assert_eq!(boundary, tri_boundary);
+}
+
+#[test]
+fn edge_list_boundary_edges_keep_inside_on_the_left() {
+    let mut tess = Tessellator::new();
+    polygon_with_hole(&mut tess);
+    assert!(tess.tessellate(WindingRule::Positive, ElementType::EdgeList, 3, 2, None));
+
+    let verts = tess.vertices();
+    let elems = tess.elements();
+    let (cx, cy) = (5.0, 5.0);
+    for (i, &flag) in tess.edge_flags().iter().enumerate() {
+        if flag == 0 {
+            continue;
+        }
+        let (a, b) = (elems[i * 2] as usize, elems[i * 2 + 1] as usize);
+        let (ax, ay, bx, by) = (verts[a * 2], verts[a * 2 + 1], verts[b * 2], verts[b * 2 + 1]);
+        let side = helpers::triangle_area(ax, ay, bx, by, cx, cy);
+        let on_hole = ax > 2.0 && ax < 8.0 && ay > 2.0 && ay < 8.0;
+        // Outer edges see the centre on their left, hole edges on their right.
+        assert_eq!(side > 0.0, !on_hole, "edge {} has the wrong orientation", i);
+    }
+}
+
+#[test]
+fn edge_list_with_merged_polygons_drops_merged_diagonals() {
+    let mut tess = Tessellator::new();
+    tess.add_contour(2, &[0.0f64, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0]);
+    assert!(tess.tessellate(WindingRule::Positive, ElementType::EdgeList, 4, 2, None));
+    assert_eq!(tess.element_count(), 4);
+    assert!(tess.edge_flags().iter().all(|&f| f == 1));
+}
@@ -253,3 +253,42 @@ fn boundary_contours_nested_squares() {
         "nested squares with Odd should produce >= 2 contours"
     );
 }
+
+#[test]
+fn boundary_contours_emit_whole_rings_like_libtess2() {
+    // Before the contour tree, BoundaryContours emitted every inside
+    // triangle as its own ring; like C tessTesselate it now emits the
+    // boundary of the fill, one ring per boundary loop.
+    let (outer, inner) = polygon_with_hole();
+    let mut tess = Tessellator::new();
+    tess.add_contour(2, &outer);
+    tess.add_contour(2, &inner);
+    assert!(tess.tessellate(WindingRule::Odd, ElementType::BoundaryContours, 0, 2, None));
+    assert_eq!(tess.element_count(), 2);
+    let mut counts: Vec<u32> = tess.elements().chunks(2).map(|e| e[1]).collect();
+    counts.sort();
+    assert_eq!(counts, vec![4, 4]);
+    let area: f64 = tess
+        .elements()
+        .chunks(2)
+        .map(|e| {
+            let (start, count) = (e[0] as usize, e[1] as usize);
+            helpers::polygon_signed_area(&tess.vertices()[start * 2..(start + count) * 2])
+        })
+        .sum();
+    assert!((area.abs() - 84.0).abs() < 1e-9, "ring area {}", area);
+}
+
+#[test]
+fn triangles_of_the_fill_are_still_available_as_polygons() {
+    // The per-triangle rings the old BoundaryContours output gave are the
+    // Polygons output with poly_size 3.
+    let (outer, inner) = polygon_with_hole();
+    let mut tess = Tessellator::new();
+    tess.add_contour(2, &outer);
+    tess.add_contour(2, &inner);
+    assert!(tess.tessellate(WindingRule::Odd, ElementType::Polygons, 3, 2, None));
+    assert_eq!(tess.element_count(), 8);
+    assert!((helpers::total_tessellation_area(&tess) - 84.0).abs() < 1e-9);
+    helpers::verify_valid_output(&tess);
+}
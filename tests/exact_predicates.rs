@@ -0,0 +1,121 @@
+// Copyright 2025 Lars Brubaker
+// Tests for TessOption::ExactPredicates (adaptive-precision sweep decisions).
+
+mod helpers;
+
+use tess2_rust::{ElementType, TessOption, Tessellator, WindingRule};
+
+fn run(contours: &[Vec<f64>], exact: bool, element_type: ElementType) -> Tessellator {
+    let mut tess = Tessellator::new();
+    tess.set_option(TessOption::ExactPredicates, exact);
+    for c in contours {
+        tess.add_contour(2, c);
+    }
+    assert!(tess.tessellate(WindingRule::Positive, element_type, 3, 2, None));
+    helpers::verify_valid_output(&tess);
+    tess
+}
+
+fn rotate(contour: &[f64], angle: f64) -> Vec<f64> {
+    let (s, c) = angle.sin_cos();
+    contour
+        .chunks(2)
+        .flat_map(|p| [p[0] * c - p[1] * s, p[0] * s + p[1] * c])
+        .collect()
+}
+
+/// A long sawtooth whose teeth are a few ulps tall: every vertex is
+/// nearly collinear with its neighbours.
+fn hairline_sawtooth() -> Vec<f64> {
+    let mut c = vec![0.0, -1.0];
+    for i in 0..=40 {
+        let x = i as f64 * 0.25;
+        let y = if i % 2 == 0 { 0.0 } else { 1e-15 * (1.0 + i as f64) };
+        c.extend([x, y]);
+    }
+    c.extend([10.0, -1.0]);
+    c
+}
+
+#[test]
+fn ordinary_input_matches_default() {
+    let contours = vec![
+        vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0],
+        vec![1.0, 1.0, 1.0, 3.0, 3.0, 3.0, 3.0, 1.0],
+        vec![2.0, -1.0, 5.0, 2.0, 2.0, 5.0, -1.0, 2.0],
+    ];
+    let plain = run(&contours, false, ElementType::Polygons);
+    let exact = run(&contours, true, ElementType::Polygons);
+    assert_eq!(exact.vertices(), plain.vertices());
+    assert_eq!(exact.elements(), plain.elements());
+}
+
+#[test]
+fn near_collinear_rotations_keep_area() {
+    let saw = hairline_sawtooth();
+    let expected = helpers::polygon_signed_area(&saw).abs();
+    for step in 0..90 {
+        let contour = rotate(&saw, step as f64 * 0.0697);
+        let tess = run(&[contour], true, ElementType::Polygons);
+        let area = helpers::total_tessellation_area(&tess);
+        assert!((area - expected).abs() < 1e-9, "step {}: area {} vs {}", step, area, expected);
+    }
+}
+
+#[test]
+fn self_intersecting_rotations_stay_valid() {
+    // Bow ties and a pentagram whose crossings land close to other edges.
+    let star: Vec<f64> = (0..5)
+        .flat_map(|i| {
+            let a = (i * 2) as f64 * std::f64::consts::TAU / 5.0;
+            [a.cos(), a.sin()]
+        })
+        .collect();
+    let bowtie = vec![0.0, 0.0, 1.0, 1e-12, 1.0, 1.0, 0.0, 1.0 - 1e-12];
+    for step in 0..60 {
+        let angle = step as f64 * 0.1047;
+        let contours = vec![rotate(&star, angle), rotate(&bowtie, angle)];
+        let plain = run(&contours, false, ElementType::Polygons);
+        let exact = run(&contours, true, ElementType::Polygons);
+        let (a, b) = (helpers::total_tessellation_area(&plain), helpers::total_tessellation_area(&exact));
+        assert!((a - b).abs() < 1e-9, "step {}: {} vs {}", step, a, b);
+    }
+}
+
+#[test]
+fn convex_partition_in_exact_mode() {
+    let saw = hairline_sawtooth();
+    let tess = run(std::slice::from_ref(&saw), true, ElementType::ConvexPartition);
+    assert!(tess.element_count() >= 1);
+    let expected = helpers::polygon_signed_area(&saw).abs();
+    let verts = tess.vertices();
+    let area: f64 = tess
+        .elements()
+        .chunks(2)
+        .map(|p| {
+            let ring: Vec<f64> = (p[0]..p[0] + p[1])
+                .flat_map(|i| [verts[i as usize * 2], verts[i as usize * 2 + 1]])
+                .collect();
+            helpers::polygon_signed_area(&ring)
+        })
+        .sum();
+    assert!((area.abs() - expected).abs() < 1e-9);
+}
+
+#[test]
+fn vertex_just_below_an_edge_stays_off_it() {
+    // The apex of the lower triangle is the double nearest 1/3, which lies
+    // just below the upper triangle's edge from (0, 0) to (3, 1).  Rounded
+    // arithmetic puts it on the edge and bends the edge through it; the
+    // exact test keeps the triangles apart.
+    let contours = vec![vec![0.0, 0.0, 3.0, 1.0, 0.0, 1.0], vec![1.0, 1.0 / 3.0, 0.5, -1.0, 1.5, -1.0]];
+    let plain = run(&contours, false, ElementType::Polygons);
+    assert_eq!(plain.element_count(), 3);
+    let exact = run(&contours, true, ElementType::Polygons);
+    assert_eq!(exact.element_count(), 2);
+    for tri in exact.elements().chunks(3) {
+        let mut idx: Vec<u32> = tri.iter().map(|&v| exact.vertex_indices()[v as usize]).collect();
+        idx.sort();
+        assert!(idx == [0, 1, 2] || idx == [3, 4, 5], "mixed triangle {:?}", idx);
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright 2025 Lars Brubaker
+// Tests for the anti-aliasing fringe built from edge flags.
+
+use tess2_rust::fringe::{Fringe, MITER_LIMIT};
+use tess2_rust::{ElementType, Tessellator, WindingRule};
+
+fn fringe(
+    contours: &[Vec<f64>],
+    element_type: ElementType,
+    width: f64,
+    normal: Option<[f64; 3]>,
+) -> (Tessellator, Fringe) {
+    let mut tess = Tessellator::new();
+    for c in contours {
+        tess.add_contour(2, c);
+    }
+    assert!(tess.tessellate(WindingRule::Odd, element_type, 3, 2, normal));
+    let f = Fringe::new(tess.vertices(), 2, tess.elements(), tess.edge_flags(), element_type, 3, width);
+    (tess, f)
+}
+
+fn point(f: &Fringe, v: u32) -> [f64; 2] {
+    [f.vertices()[v as usize * 2], f.vertices()[v as usize * 2 + 1]]
+}
+
+fn signed_areas(f: &Fringe) -> Vec<f64> {
+    f.triangles()
+        .chunks_exact(3)
+        .map(|t| {
+            let (a, b, c) = (point(f, t[0]), point(f, t[1]), point(f, t[2]));
+            0.5 * ((b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0]))
+        })
+        .collect()
+}
+
+/// Distance from each rim vertex to the tessellation vertex it came from.
+fn rim_offsets(tess: &Tessellator, f: &Fringe) -> Vec<f64> {
+    (0..f.vertex_count())
+        .filter(|&v| f.coverage()[v] == 0.0)
+        .map(|v| {
+            let p = point(f, v as u32);
+            let s = f.sources()[v] as usize;
+            (p[0] - tess.vertices()[s * 2]).hypot(p[1] - tess.vertices()[s * 2 + 1])
+        })
+        .collect()
+}
+
+fn square_with_hole() -> Vec<Vec<f64>> {
+    vec![vec![0.0, 0.0, 6.0, 0.0, 6.0, 6.0, 0.0, 6.0], vec![2.0, 2.0, 2.0, 4.0, 4.0, 4.0, 4.0, 2.0]]
+}
+
+#[test]
+fn square_with_hole_gets_mitered_strips() {
+    let w = 0.25;
+    for element_type in [ElementType::Polygons, ElementType::ConnectedPolygons] {
+        let (tess, f) = fringe(&square_with_hole(), element_type, w, None);
+        // Eight boundary edges, one quad each, and one inner and one miter
+        // vertex per corner; interior edges add nothing.
+        assert_eq!(f.triangles().len(), 8 * 6);
+        assert_eq!(f.vertex_count(), 16);
+        assert_eq!(f.coverage().iter().filter(|&&c| c == 1.0).count(), 8);
+        for d in rim_offsets(&tess, &f) {
+            assert!((d - w * 2f64.sqrt()).abs() < 1e-12);
+        }
+        let areas = signed_areas(&f);
+        assert!(areas.iter().all(|&a| a > 0.0), "{:?}", areas);
+        let expected = (6.0 + 2.0 * w) * (6.0 + 2.0 * w) - 36.0 + 4.0 - (2.0 - 2.0 * w) * (2.0 - 2.0 * w);
+        assert!((areas.iter().sum::<f64>() - expected).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn fringe_winds_like_the_fill() {
+    let (_, f) = fringe(&square_with_hole(), ElementType::Polygons, 0.5, Some([0.0, 0.0, -1.0]));
+    assert!(signed_areas(&f).iter().all(|&a| a < 0.0));
+}
+
+#[test]
+fn sharp_corners_are_beveled() {
+    let w = 0.1;
+    let (tess, f) = fringe(&[vec![0.0, 0.0, 10.0, 0.5, 0.0, 1.0]], ElementType::Polygons, w, None);
+    // Three quads and one bevel triangle at the spike.
+    assert_eq!(f.triangles().len(), 3 * 6 + 3);
+    assert_eq!(f.vertex_count(), 3 + 2 + 2);
+    for d in rim_offsets(&tess, &f) {
+        assert!(d <= MITER_LIMIT * w + 1e-12 && d >= w - 1e-12, "{}", d);
+    }
+    assert!(signed_areas(&f).iter().all(|&a| a > 0.0));
+}
+
+#[test]
+fn touching_corners_chain_around_the_outside() {
+    let w = 0.25;
+    let bowtie = vec![vec![0.0, 0.0, 2.0, 0.0, 2.0, 2.0, 0.0, 2.0], vec![2.0, 2.0, 4.0, 2.0, 4.0, 4.0, 2.0, 4.0]];
+    let (tess, f) = fringe(&bowtie, ElementType::Polygons, w, None);
+    assert_eq!(tess.vertex_count(), 7);
+    assert_eq!(f.triangles().len(), 8 * 6);
+    // Every corner, the shared one included, is a right-angled convex
+    // corner of one square.
+    for d in rim_offsets(&tess, &f) {
+        assert!((d - w * 2f64.sqrt()).abs() < 1e-12, "{}", d);
+    }
+    assert!(signed_areas(&f).iter().all(|&a| a > 0.0));
+}
+
+#[test]
+fn other_element_types_give_no_fringe() {
+    let (_, f) = fringe(&square_with_hole(), ElementType::BoundaryContours, 1.0, None);
+    assert_eq!(f.vertex_count(), 0);
+    assert!(f.triangles().is_empty());
+}
@@ -0,0 +1,198 @@
+// Copyright 2025 Lars Brubaker
+// Tests for incremental re-tessellation (insert/update/remove_contour, retessellate).
+
+mod helpers;
+
+use tess2_rust::{ElementType, Orientation, TessOption, TessStatus, Tessellator, WindingRule};
+
+/// A star with `n` spikes around (`cx`, `cy`); `clockwise` reverses it.
+fn star(n: usize, cx: f64, cy: f64, radius: f64, clockwise: bool) -> Vec<f64> {
+    let mut pts: Vec<[f64; 2]> = (0..2 * n)
+        .map(|i| {
+            let a = 0.3 + i as f64 * std::f64::consts::PI / n as f64;
+            let r = if i % 2 == 0 { radius } else { radius * 0.4 };
+            [cx + r * a.cos(), cy + r * a.sin()]
+        })
+        .collect();
+    if clockwise {
+        pts.reverse();
+    }
+    pts.concat()
+}
+
+/// Triangles as coordinate triples, each rotated to start at its smallest
+/// corner and the whole list sorted, so runs can be compared as sets.
+fn triangle_set(tess: &Tessellator) -> Vec<[[u64; 2]; 3]> {
+    let verts = tess.vertices();
+    let mut tris: Vec<[[u64; 2]; 3]> = tess
+        .elements()
+        .chunks(3)
+        .map(|tri| {
+            let p = |k: usize| {
+                let i = tri[k] as usize;
+                [verts[i * 2].to_bits(), verts[i * 2 + 1].to_bits()]
+            };
+            let mut t = [p(0), p(1), p(2)];
+            let first = (0..3).min_by_key(|&k| t[k]).unwrap();
+            t.rotate_left(first);
+            t
+        })
+        .collect();
+    tris.sort_unstable();
+    tris
+}
+
+fn full_run(contours: &[&Vec<f64>], winding_rule: WindingRule, element_type: ElementType) -> Tessellator {
+    let mut tess = Tessellator::new();
+    for c in contours {
+        tess.add_contour(2, c);
+    }
+    assert!(tess.tessellate(winding_rule, element_type, 3, 2, None));
+    tess
+}
+
+/// A 4×4 map of stars; every other one has a second star overlapping it.
+fn star_map() -> Vec<Vec<f64>> {
+    let mut contours = Vec::new();
+    for i in 0..16 {
+        let (cx, cy) = ((i % 4) as f64 * 20.0, (i / 4) as f64 * 20.0);
+        contours.push(star(5, cx, cy, 6.0, false));
+        if i % 2 == 0 {
+            contours.push(star(7, cx + 3.0, cy + 1.0, 5.0, true));
+        }
+    }
+    contours
+}
+
+fn assert_matches_full_run(tess: &mut Tessellator, live: &[Option<Vec<f64>>], winding_rule: WindingRule) {
+    assert!(tess.retessellate(winding_rule, ElementType::Polygons, 3, 2, None));
+    helpers::verify_valid_output(tess);
+    let contours: Vec<&Vec<f64>> = live.iter().flatten().collect();
+    let full = full_run(&contours, winding_rule, ElementType::Polygons);
+    assert_eq!(triangle_set(tess), triangle_set(&full));
+    assert_eq!(tess.vertex_count(), full.vertex_count());
+
+    // Vertex indices refer to the kept contours' vertices in handle order.
+    let originals: Vec<f64> = contours.iter().flat_map(|c| c.iter().copied()).collect();
+    for (v, &idx) in tess.vertex_indices().iter().enumerate() {
+        if idx != tess2_rust::tess::TESS_UNDEF {
+            let i = idx as usize;
+            assert_eq!(&tess.vertices()[v * 2..v * 2 + 2], &originals[i * 2..i * 2 + 2]);
+        }
+    }
+}
+
+#[test]
+fn edits_match_full_run_and_resweep_only_touched_clusters() {
+    let mut live: Vec<Option<Vec<f64>>> = star_map().into_iter().map(Some).collect();
+    let mut tess = Tessellator::new();
+    let handles: Vec<_> = live.iter().flatten().map(|c| tess.insert_contour(2, c)).collect();
+    assert_matches_full_run(&mut tess, &live, WindingRule::NonZero);
+    assert_eq!(tess.resweep_count(), live.len());
+
+    // Nothing changed: everything comes from the cache.
+    assert_matches_full_run(&mut tess, &live, WindingRule::NonZero);
+    assert_eq!(tess.resweep_count(), 0);
+
+    // Move the lone star at (20, 0) a little: only it is swept again.
+    let moved = star(5, 21.0, 1.5, 6.0, false);
+    assert!(tess.update_contour(handles[2], 2, &moved));
+    live[2] = Some(moved);
+    assert_matches_full_run(&mut tess, &live, WindingRule::NonZero);
+    assert_eq!(tess.resweep_count(), 1);
+
+    // Drag it onto the pair at (0, 0): the three merge into one cluster.
+    let onto = star(5, 6.0, 2.0, 6.0, false);
+    assert!(tess.update_contour(handles[2], 2, &onto));
+    live[2] = Some(onto);
+    assert_matches_full_run(&mut tess, &live, WindingRule::NonZero);
+    assert_eq!(tess.resweep_count(), 3);
+
+    // Add a contour across two stars of the top row and remove one elsewhere.
+    let bar = vec![40.0, 59.0, 62.0, 59.0, 62.0, 61.0, 40.0, 61.0];
+    tess.insert_contour(2, &bar);
+    live.push(Some(bar));
+    assert!(tess.remove_contour(handles[5]));
+    live[5] = None;
+    assert_matches_full_run(&mut tess, &live, WindingRule::NonZero);
+    assert!(tess.resweep_count() <= 4, "resweeps {}", tess.resweep_count());
+
+    // A new winding rule invalidates every cluster.
+    assert_matches_full_run(&mut tess, &live, WindingRule::Odd);
+    assert_eq!(tess.resweep_count(), live.iter().flatten().count());
+}
+
+#[test]
+fn box_overlapping_an_earlier_wider_one_joins_its_cluster() {
+    // C lies inside the long A, but the narrow B starts between them.
+    let live = vec![
+        Some(vec![0.0, 0.0, 100.0, 0.0, 100.0, 1.0, 0.0, 1.0]),
+        Some(vec![10.0, 5.0, 20.0, 5.0, 20.0, 6.0, 10.0, 6.0]),
+        Some(vec![50.0, 0.0, 60.0, 0.0, 60.0, 1.0, 50.0, 1.0]),
+    ];
+    let mut tess = Tessellator::new();
+    for c in live.iter().flatten() {
+        tess.insert_contour(2, c);
+    }
+    assert_matches_full_run(&mut tess, &live, WindingRule::Odd);
+    assert!((helpers::total_tessellation_area(&tess) - 100.0).abs() < 1e-9);
+}
+
+#[test]
+fn clockwise_input_keeps_full_run_orientation() {
+    let contours = [star(5, 0.0, 0.0, 4.0, true), star(6, 30.0, 0.0, 4.0, true)];
+    let mut tess = Tessellator::new();
+    tess.set_contour_orientation(Some(Orientation::Clockwise));
+    for c in &contours {
+        tess.insert_contour(2, c);
+    }
+    let live: Vec<Option<Vec<f64>>> = contours.iter().cloned().map(Some).collect();
+    assert_matches_full_run(&mut tess, &live, WindingRule::NonZero);
+    let full = full_run(&contours.iter().collect::<Vec<_>>(), WindingRule::NonZero, ElementType::Polygons);
+    let sign = helpers::total_tessellation_signed_area(&full).signum();
+    assert_eq!(helpers::total_tessellation_signed_area(&tess).signum(), sign);
+
+    let mut full = Tessellator::new();
+    full.set_contour_orientation(Some(Orientation::Clockwise));
+    for c in &contours {
+        full.add_contour(2, c);
+    }
+    assert!(full.tessellate(WindingRule::NonZero, ElementType::BoundaryContours, 3, 2, None));
+    assert!(tess.retessellate(WindingRule::NonZero, ElementType::BoundaryContours, 3, 2, None));
+    assert_eq!(tess.contour_tree().len(), full.contour_tree().len());
+    for (a, b) in tess.contour_tree().iter().zip(full.contour_tree()) {
+        assert_eq!(a.orientation, b.orientation);
+        assert_eq!(a.is_hole, b.is_hole);
+    }
+}
+
+#[test]
+fn stale_handles_are_rejected() {
+    let mut tess = Tessellator::new();
+    let h = tess.insert_contour(2, &[0.0, 0.0, 1.0, 0.0, 0.0, 1.0]);
+    assert!(tess.remove_contour(h));
+    assert!(!tess.remove_contour(h));
+    assert!(!tess.update_contour(h, 2, &[0.0, 0.0, 2.0, 0.0, 0.0, 2.0]));
+    assert!(tess.retessellate(WindingRule::Odd, ElementType::Polygons, 3, 2, None));
+    assert_eq!(tess.element_count(), 0);
+}
+
+#[test]
+fn unsupported_input_fails_the_call() {
+    let square = [0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0];
+    let mut tess = Tessellator::new();
+    tess.insert_contour(2, &square);
+    tess.add_points(2, &[1.0, 1.0]);
+    assert!(!tess.retessellate(WindingRule::Odd, ElementType::Polygons, 3, 2, None));
+    assert_eq!(tess.get_status(), TessStatus::InvalidInput);
+
+    tess.reset();
+    tess.set_option(TessOption::Voronoi, true);
+    assert!(!tess.retessellate(WindingRule::Odd, ElementType::Polygons, 3, 2, None));
+    assert_eq!(tess.get_status(), TessStatus::InvalidInput);
+
+    tess.reset();
+    tess.set_option(TessOption::Voronoi, false);
+    assert!(tess.retessellate(WindingRule::Odd, ElementType::Polygons, 3, 2, None));
+    assert_eq!(tess.element_count(), 2);
+}
@@ -0,0 +1,85 @@
+// Copyright 2025 Lars Brubaker
+// Tests for TessOption::KeepMesh and the read-only mesh queries.
+
+mod helpers;
+
+use tess2_rust::tess::TESS_UNDEF;
+use tess2_rust::{ElementType, TessOption, TessStatus, Tessellator, WindingRule};
+
+fn square_with_hole(element_type: ElementType) -> Tessellator {
+    let mut tess = Tessellator::new();
+    tess.set_option(TessOption::KeepMesh, true);
+    tess.add_contour(2, &[0.0, 0.0, 6.0, 0.0, 6.0, 6.0, 0.0, 6.0]);
+    tess.add_contour(2, &[2.0, 2.0, 2.0, 4.0, 4.0, 4.0, 4.0, 2.0]);
+    assert!(tess.tessellate(WindingRule::Odd, element_type, 3, 2, None));
+    tess
+}
+
+#[test]
+fn mesh_is_only_kept_on_request() {
+    let plain = helpers::tessellate_contours(&[vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0]], WindingRule::Odd);
+    assert!(plain.mesh().is_none());
+
+    let mut tess = square_with_hole(ElementType::Polygons);
+    let report = tess.mesh().unwrap().check();
+    assert!(report.is_ok(), "{}", report);
+    tess.reset();
+    assert!(tess.mesh().is_none());
+}
+
+#[test]
+fn inside_faces_match_the_triangles() {
+    let tess = square_with_hole(ElementType::ConnectedPolygons);
+    let mesh = tess.mesh().unwrap();
+    assert_eq!(mesh.inside_faces().count(), tess.element_count());
+
+    let elements = tess.elements();
+    for f in mesh.inside_faces() {
+        let n = mesh.faces[f as usize].n as usize;
+        let poly = &elements[n * 6..n * 6 + 6];
+        let corners: Vec<u32> = mesh.face_vertices(f).map(|v| mesh.verts[v as usize].n).collect();
+        assert_eq!(corners, &poly[..3]);
+
+        // The neighbour across each loop edge is the ConnectedPolygons one.
+        let neighbours: Vec<u32> = mesh
+            .face_neighbors(f)
+            .map(|g| g.map_or(TESS_UNDEF, |g| mesh.faces[g as usize].n))
+            .collect();
+        assert_eq!(neighbours, &poly[3..]);
+    }
+}
+
+#[test]
+fn one_rings_are_symmetric() {
+    let tess = square_with_hole(ElementType::Polygons);
+    let mesh = tess.mesh().unwrap();
+    assert_eq!(mesh.vertex_ids().count(), 8);
+    for v in mesh.vertex_ids() {
+        for e in mesh.vertex_edges(v) {
+            assert_eq!(mesh.edges[e as usize].org, v);
+        }
+        for u in mesh.one_ring(v) {
+            assert!(mesh.one_ring(u).any(|w| w == v));
+        }
+    }
+    // Only the fill is kept: every edge has an inside face on at least one
+    // side, and border edges have none on the other.
+    for e in mesh.edge_ids() {
+        match mesh.edge_faces(e) {
+            (Some(l), Some(r)) => assert!(mesh.faces[l as usize].inside && mesh.faces[r as usize].inside),
+            (Some(f), None) | (None, Some(f)) => assert!(mesh.faces[f as usize].inside),
+            (None, None) => panic!("edge {} has no faces", e),
+        }
+    }
+}
+
+#[test]
+fn failed_run_drops_the_kept_mesh() {
+    let mut tess = square_with_hole(ElementType::Polygons);
+    assert!(tess.mesh().is_some());
+    tess.set_planarity_tolerance(0.1);
+    tess.add_contour(3, &[0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 10.0, 5.0, 0.0, 10.0, 0.0]);
+    assert!(!tess.tessellate(WindingRule::Odd, ElementType::Polygons, 3, 3, None));
+    assert_eq!(tess.get_status(), TessStatus::NonPlanar);
+    assert!(tess.mesh().is_none());
+}
@@ -0,0 +1,64 @@
+// Copyright 2025 Lars Brubaker
+// Tests for TessOption::CheckMesh (mesh invariants checked after every sweep event).
+
+mod helpers;
+
+use tess2_rust::mesh::Mesh;
+use tess2_rust::{ElementType, TessOption, Tessellator, WindingRule};
+
+fn checked(contours: &[Vec<f64>], winding_rule: WindingRule) -> Tessellator {
+    let mut tess = Tessellator::new();
+    tess.set_option(TessOption::CheckMesh, true);
+    for c in contours {
+        tess.add_contour(2, c);
+    }
+    assert!(tess.tessellate(winding_rule, ElementType::Polygons, 3, 2, None));
+    helpers::verify_valid_output(&tess);
+    tess
+}
+
+#[test]
+fn testbed_data_keeps_invariants() {
+    for data in [
+        include_str!("data/dude.dat"),
+        include_str!("data/funny.dat"),
+        include_str!("data/kzer_za.dat"),
+        include_str!("data/glu_winding.dat"),
+    ] {
+        let contours = helpers::parse_contours(data);
+        for rule in [WindingRule::Odd, WindingRule::NonZero, WindingRule::AbsGeqTwo] {
+            let tess = checked(&contours, rule);
+            let plain = helpers::tessellate_contours(&contours, rule);
+            assert_eq!(tess.elements(), plain.elements());
+        }
+    }
+}
+
+#[test]
+fn self_intersecting_stars_keep_invariants() {
+    for step in 0..30 {
+        let angle = step as f64 * 0.21;
+        let contours: Vec<Vec<f64>> = [(7, 1.0), (5, -1.3), (9, 0.7)]
+            .iter()
+            .map(|&(n, spin)| {
+                (0..n)
+                    .flat_map(|i| {
+                        let a = angle * spin + (i * (n / 2)) as f64 * std::f64::consts::TAU / n as f64;
+                        [10.0 * a.cos(), 10.0 * a.sin()]
+                    })
+                    .collect()
+            })
+            .collect();
+        checked(&contours, WindingRule::Odd);
+    }
+}
+
+#[test]
+fn public_check_reports_a_fresh_mesh_as_valid() {
+    let mut mesh = Mesh::new();
+    let e = mesh.make_edge().unwrap();
+    mesh.add_edge_vertex(e).unwrap();
+    let report = mesh.check();
+    assert!(report.is_ok(), "{}", report);
+    assert_eq!((report.vertices, report.edges), (3, 2));
+}
@@ -0,0 +1,95 @@
+// Copyright 2025 Lars Brubaker
+// Tests for the navigation mesh (point location, A* corridors, funnel paths).
+
+use tess2_rust::navmesh::NavMesh;
+use tess2_rust::{ElementType, Tessellator, WindingRule};
+
+fn navmesh(contours: &[Vec<f64>], poly_size: usize, normal: Option<[f64; 3]>) -> NavMesh {
+    let mut tess = Tessellator::new();
+    for c in contours {
+        tess.add_contour(2, c);
+    }
+    assert!(tess.tessellate(WindingRule::Odd, ElementType::ConnectedPolygons, poly_size, 2, normal));
+    NavMesh::new(tess.vertices(), 2, tess.elements(), poly_size)
+}
+
+/// A 10×10 room with a 6×6 pillar in the middle.
+fn room_with_pillar() -> Vec<Vec<f64>> {
+    vec![
+        vec![0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0],
+        vec![2.0, 2.0, 2.0, 8.0, 8.0, 8.0, 8.0, 2.0],
+    ]
+}
+
+fn length(path: &[[f64; 2]]) -> f64 {
+    path.windows(2).map(|w| (w[1][0] - w[0][0]).hypot(w[1][1] - w[0][1])).sum()
+}
+
+#[test]
+fn path_bends_round_the_pillar_corners() {
+    let expected = 6.0 + 2.0 * 10f64.sqrt();
+    for poly_size in [3, 6] {
+        for normal in [None, Some([0.0, 0.0, -1.0])] {
+            let nav = navmesh(&room_with_pillar(), poly_size, normal);
+            let path = nav.find_path([1.0, 5.0], [9.0, 5.0]).unwrap();
+            assert!((length(&path) - expected).abs() < 1e-9, "{:?}", path);
+            assert_eq!(path.len(), 4, "{:?}", path);
+            for corner in &path[1..3] {
+                assert!([2.0, 8.0].contains(&corner[0]) && [2.0, 8.0].contains(&corner[1]), "{:?}", path);
+            }
+        }
+    }
+}
+
+#[test]
+fn visible_goal_gives_a_straight_line() {
+    let nav = navmesh(&room_with_pillar(), 3, None);
+    let path = nav.find_path([0.5, 0.5], [9.5, 1.5]).unwrap();
+    assert_eq!(path, vec![[0.5, 0.5], [9.5, 1.5]]);
+    let path = nav.find_path([1.0, 1.0], [1.0, 1.0]).unwrap();
+    assert_eq!(path, vec![[1.0, 1.0]]);
+}
+
+#[test]
+fn locate_walks_to_every_polygon() {
+    let nav = navmesh(&room_with_pillar(), 3, None);
+    for p in 0..nav.polygon_count() {
+        let corners = nav.polygon(p);
+        let mut c = [0.0, 0.0];
+        for &v in corners {
+            let xy = nav.vertex(v);
+            c = [c[0] + xy[0], c[1] + xy[1]];
+        }
+        let c = [c[0] / corners.len() as f64, c[1] / corners.len() as f64];
+        for hint in [None, Some(0), Some(nav.polygon_count() - 1)] {
+            assert_eq!(nav.locate(c, hint), Some(p));
+        }
+    }
+}
+
+#[test]
+fn off_mesh_and_disconnected_goals_have_no_path() {
+    let nav = navmesh(&room_with_pillar(), 3, None);
+    assert_eq!(nav.locate([5.0, 5.0], None), None);
+    assert!(nav.find_path([1.0, 1.0], [5.0, 5.0]).is_none());
+    assert!(nav.find_path([1.0, 1.0], [20.0, 1.0]).is_none());
+
+    let islands = vec![
+        vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0],
+        vec![6.0, 0.0, 10.0, 0.0, 10.0, 4.0, 6.0, 4.0],
+    ];
+    let nav = navmesh(&islands, 3, None);
+    assert!(nav.locate([8.0, 2.0], None).is_some());
+    assert!(nav.find_path([2.0, 2.0], [8.0, 2.0]).is_none());
+}
+
+#[test]
+fn corridor_with_a_gap_has_no_path() {
+    let nav = navmesh(&room_with_pillar(), 3, None);
+    let (start, goal) = ([1.0, 5.0], [9.0, 5.0]);
+    let (from, to) = (nav.locate(start, None).unwrap(), nav.locate(goal, None).unwrap());
+    let corridor = nav.find_corridor(from, start, to, goal).unwrap();
+    assert!(nav.string_pull(&corridor, start, goal).is_some());
+    assert!(!nav.neighbors(from).any(|p| p == to));
+    assert!(nav.string_pull(&[from, to], start, goal).is_none());
+}
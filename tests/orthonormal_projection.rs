@@ -0,0 +1,133 @@
+// Copyright 2025 Lars Brubaker
+// Tests for TessOption::OrthonormalProjection on tilted 3D faces.
+
+mod helpers;
+
+use tess2_rust::{ElementType, TessOption, Tessellator, WindingRule};
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+/// Orthonormal axes (u, v) of a plane facing (1, 1.1, 1.2), which no
+/// coordinate plane projects without strong shear.
+fn tilted_plane() -> ([f64; 3], [f64; 3]) {
+    let len = dot([1.0, 1.1, 1.2], [1.0, 1.1, 1.2]).sqrt();
+    let n = [1.0 / len, 1.1 / len, 1.2 / len];
+    let w = n[0] - n[1];
+    let u = [1.0 - w * n[0], -1.0 - w * n[1], -w * n[2]];
+    let len = dot(u, u).sqrt();
+    let u = [u[0] / len, u[1] / len, u[2] / len];
+    let v = [n[1] * u[2] - n[2] * u[1], n[2] * u[0] - n[0] * u[2], n[0] * u[1] - n[1] * u[0]];
+    (u, v)
+}
+
+fn lift(contours: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let (u, v) = tilted_plane();
+    contours
+        .iter()
+        .map(|c| c.chunks(2).flat_map(|p| (0..3).map(move |k| p[0] * u[k] + p[1] * v[k] + 5.0)).collect())
+        .collect()
+}
+
+fn tessellate(contours: &[Vec<f64>], size: usize, orthonormal: bool, setup: impl Fn(&mut Tessellator)) -> Tessellator {
+    let mut tess = Tessellator::new();
+    tess.set_option(TessOption::OrthonormalProjection, orthonormal);
+    setup(&mut tess);
+    for c in contours {
+        tess.add_contour(size, c);
+    }
+    assert!(tess.tessellate(WindingRule::Odd, ElementType::Polygons, 3, size, None));
+    tess
+}
+
+/// Triangles as sorted input vertex index triples.
+fn triangles(tess: &Tessellator) -> Vec<[u32; 3]> {
+    let mut tris: Vec<[u32; 3]> = tess
+        .elements()
+        .chunks_exact(3)
+        .map(|t| {
+            let mut t = [0, 1, 2].map(|k| tess.vertex_indices()[t[k] as usize]);
+            t.sort();
+            t
+        })
+        .collect();
+    tris.sort();
+    tris
+}
+
+fn cdt(tess: &mut Tessellator) {
+    tess.set_option(TessOption::ConstrainedDelaunayTriangulation, true);
+}
+
+#[test]
+fn delaunay_on_a_tilted_face_matches_the_flat_one() {
+    for data in [include_str!("data/dude.dat"), include_str!("data/funny.dat")] {
+        let contours = helpers::parse_contours(data);
+        let flat = triangles(&tessellate(&contours, 2, false, cdt));
+        let lifted = lift(&contours);
+        assert_eq!(triangles(&tessellate(&lifted, 3, true, cdt)), flat);
+        // The axis-aligned projection shears the face and flips diagonals.
+        assert_ne!(triangles(&tessellate(&lifted, 3, false, cdt)), flat);
+    }
+}
+
+#[test]
+fn refinement_angles_hold_on_the_face_itself() {
+    let contour = vec![vec![0.0, 0.0, 10.0, 0.0, 10.0, 3.0, 6.0, 3.5, 5.0, 8.0, 0.0, 6.0]];
+    let worst_angle = |tess: &Tessellator| {
+        let p = |i: u32| {
+            let v = &tess.vertices()[i as usize * 3..i as usize * 3 + 3];
+            [v[0], v[1], v[2]]
+        };
+        let mut worst: f64 = 180.0;
+        for t in tess.elements().chunks_exact(3) {
+            for k in 0..3 {
+                let (a, b, c) = (p(t[k]), p(t[(k + 1) % 3]), p(t[(k + 2) % 3]));
+                let (d1, d2) = ([b[0] - a[0], b[1] - a[1], b[2] - a[2]], [c[0] - a[0], c[1] - a[1], c[2] - a[2]]);
+                worst = worst.min((dot(d1, d2) / (dot(d1, d1) * dot(d2, d2)).sqrt()).acos().to_degrees());
+            }
+        }
+        worst
+    };
+    let min_angle = |tess: &mut Tessellator| tess.set_min_angle(25.0);
+    let lifted = lift(&contour);
+    assert!(worst_angle(&tessellate(&lifted, 3, true, min_angle)) >= 25.0 - 1e-9);
+    assert!(worst_angle(&tessellate(&lifted, 3, false, min_angle)) < 25.0);
+}
+
+#[test]
+fn basis_is_orthonormal_and_two_d_input_is_unchanged() {
+    let contours = helpers::parse_contours(include_str!("data/dude.dat"));
+    let tess = tessellate(&lift(&contours), 3, true, |_| {});
+    let (s, t) = tess.projection_basis();
+    let n = tess.normal();
+    for (a, b, expected) in [(s, s, 1.0), (t, t, 1.0), (s, t, 0.0), (s, n, 0.0), (t, n, 0.0)] {
+        assert!((dot(a, b) - expected).abs() < 1e-12);
+    }
+
+    let plain = tessellate(&contours, 2, false, cdt);
+    let ortho = tessellate(&contours, 2, true, cdt);
+    assert_eq!(plain.vertices(), ortho.vertices());
+    assert_eq!(plain.elements(), ortho.elements());
+    assert_eq!(plain.projection_basis(), ortho.projection_basis());
+}
+
+#[test]
+fn retessellate_uses_the_same_plane() {
+    let contours = lift(&[
+        vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0],
+        vec![1.0, 1.0, 3.0, 1.5, 2.0, 3.0],
+        vec![10.0, 0.0, 14.0, 0.0, 12.0, 3.0, 11.0, 1.0],
+    ]);
+    let full = tessellate(&contours, 3, true, cdt);
+    let mut inc = Tessellator::new();
+    inc.set_option(TessOption::OrthonormalProjection, true);
+    cdt(&mut inc);
+    for c in &contours {
+        inc.insert_contour(3, c);
+    }
+    assert!(inc.retessellate(WindingRule::Odd, ElementType::Polygons, 3, 3, None));
+    assert_eq!(triangles(&inc), triangles(&full));
+    assert_eq!(inc.projection_basis(), full.projection_basis());
+}
@@ -0,0 +1,62 @@
+// Copyright 2025 Lars Brubaker
+// Tests for the best-fit plane and the planarity tolerance.
+
+use tess2_rust::{ElementType, TessStatus, Tessellator, WindingRule};
+
+/// A unit square with two opposite corners lifted by `lift`.
+fn saddle(lift: f64) -> Vec<f64> {
+    vec![0.0, 0.0, 0.0, 1.0, 0.0, lift, 1.0, 1.0, 0.0, 0.0, 1.0, lift]
+}
+
+#[test]
+fn planar_contours_fit_exactly() {
+    let mut tess = Tessellator::new();
+    assert!(tess.fit_plane().is_none());
+    // A counter-clockwise square on the plane z = 10 + x.
+    tess.add_contour(3, &[0.0, 0.0, 10.0, 2.0, 0.0, 12.0, 2.0, 2.0, 12.0, 0.0, 2.0, 10.0]);
+    let fit = tess.fit_plane().unwrap();
+    let h = 0.5f64.sqrt();
+    for (a, b) in fit.normal.iter().zip([-h, 0.0, h]) {
+        assert!((a - b).abs() < 1e-12, "{:?}", fit);
+    }
+    assert_eq!(fit.centroid, [1.0, 1.0, 11.0]);
+    assert!(fit.max_deviation < 1e-12);
+    assert!((fit.distance([1.0, 1.0, 12.0]) - h).abs() < 1e-12);
+
+    tess.add_contour(2, &[0.5, 0.5, 0.5, 1.5, 1.5, 1.5]);
+    assert!(tess.fit_plane().unwrap().max_deviation > 1.0);
+}
+
+#[test]
+fn warped_quad_reports_its_deviation() {
+    let mut tess = Tessellator::new();
+    tess.add_contour(3, &saddle(0.5));
+    let fit = tess.fit_plane().unwrap();
+    assert_eq!(fit.normal, [0.0, 0.0, 1.0]);
+    assert_eq!(fit.centroid, [0.5, 0.5, 0.25]);
+    assert_eq!(fit.max_deviation, 0.25);
+}
+
+#[test]
+fn tolerance_rejects_non_planar_input() {
+    let run = |tolerance: f64, contour: &[f64], size: usize| {
+        let mut tess = Tessellator::new();
+        tess.set_planarity_tolerance(tolerance);
+        tess.add_contour(size, contour);
+        let ok = tess.tessellate(WindingRule::Odd, ElementType::Polygons, 3, 3, None);
+        (ok, tess.get_status(), tess.element_count())
+    };
+    assert_eq!(run(0.1, &saddle(0.5), 3), (false, TessStatus::NonPlanar, 0));
+    assert_eq!(run(0.3, &saddle(0.5), 3), (true, TessStatus::Ok, 2));
+    assert_eq!(run(0.0, &saddle(0.5), 3), (true, TessStatus::Ok, 2));
+    assert_eq!(run(1e-9, &[0.0, 0.0, 1.0, 0.0, 1.0, 1.0], 2), (true, TessStatus::Ok, 1));
+
+    let mut tess = Tessellator::new();
+    tess.set_planarity_tolerance(0.1);
+    tess.insert_contour(3, &saddle(0.0));
+    let far = tess.insert_contour(3, &[5.0, 0.0, 0.0, 6.0, 0.0, 0.0, 6.0, 1.0, 0.0]);
+    assert!(tess.retessellate(WindingRule::Odd, ElementType::Polygons, 3, 3, None));
+    tess.update_contour(far, 3, &[5.0, 0.0, 0.0, 6.0, 0.0, 0.0, 6.0, 1.0, 1.0]);
+    assert!(!tess.retessellate(WindingRule::Odd, ElementType::Polygons, 3, 3, None));
+    assert_eq!(tess.get_status(), TessStatus::NonPlanar);
+}
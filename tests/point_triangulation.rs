@@ -0,0 +1,113 @@
+// Copyright 2025 Lars Brubaker
+// Tests for Delaunay triangulation of point sets (triangulate_points).
+
+mod helpers;
+
+use tess2_rust::Tessellator;
+
+fn triangulate(points: &[f64]) -> Tessellator {
+    let mut tess = Tessellator::new();
+    assert!(tess.triangulate_points(2, points, 2, None));
+    helpers::verify_valid_output(&tess);
+    tess
+}
+
+fn vertex(tess: &Tessellator, i: u32) -> [f64; 2] {
+    [tess.vertices()[i as usize * 2], tess.vertices()[i as usize * 2 + 1]]
+}
+
+fn in_circle(a: [f64; 2], b: [f64; 2], c: [f64; 2], d: [f64; 2]) -> f64 {
+    let (adx, ady) = (a[0] - d[0], a[1] - d[1]);
+    let (bdx, bdy) = (b[0] - d[0], b[1] - d[1]);
+    let (cdx, cdy) = (c[0] - d[0], c[1] - d[1]);
+    (adx * adx + ady * ady) * (bdx * cdy - cdx * bdy) - (bdx * bdx + bdy * bdy) * (adx * cdy - cdx * ady)
+        + (cdx * cdx + cdy * cdy) * (adx * bdy - bdx * ady)
+}
+
+fn scattered(n: usize) -> Vec<f64> {
+    let mut seed = 7u64;
+    let mut rnd = || {
+        seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (seed >> 11) as f64 / (1u64 << 53) as f64
+    };
+    (0..n * 2).map(|_| rnd() * 100.0).collect()
+}
+
+#[test]
+fn square_with_center() {
+    let tess = triangulate(&[0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0, 2.0, 2.0]);
+    assert_eq!(tess.vertex_count(), 5);
+    assert_eq!(tess.element_count(), 4);
+    let mut indices = tess.vertex_indices().to_vec();
+    indices.sort();
+    assert_eq!(indices, vec![0, 1, 2, 3, 4]);
+    assert!((helpers::total_tessellation_area(&tess) - 16.0).abs() < 1e-9);
+}
+
+#[test]
+fn scattered_points_are_delaunay() {
+    let points = scattered(300);
+    let tess = triangulate(&points);
+    assert_eq!(tess.vertex_count(), 300);
+    for (k, &idx) in tess.vertex_indices().iter().enumerate() {
+        let idx = idx as usize;
+        assert_eq!(vertex(&tess, k as u32), [points[idx * 2], points[idx * 2 + 1]]);
+    }
+    for tri in tess.elements().chunks(3) {
+        let [a, b, c] = [0, 1, 2].map(|i| vertex(&tess, tri[i]));
+        for v in 0..tess.vertex_count() as u32 {
+            if tri.contains(&v) {
+                continue;
+            }
+            assert!(in_circle(a, b, c, vertex(&tess, v)) <= 1e-6, "vertex {} inside circumcircle", v);
+        }
+    }
+}
+
+#[test]
+fn triangle_count_matches_euler() {
+    // Grid points: 4 hull corners plus 12 collinear points on the hull.
+    let mut points = Vec::new();
+    for i in 0..5 {
+        for j in 0..5 {
+            points.push(i as f64 + 0.01 * j as f64 * j as f64);
+            points.push(j as f64 + 0.01 * i as f64 * i as f64);
+        }
+    }
+    let tess = triangulate(&points);
+    assert_eq!(tess.vertex_count(), 25);
+    let hull_points = tess.edge_flags().iter().filter(|&&f| f == 1).count();
+    assert_eq!(tess.element_count(), 2 * 25 - hull_points - 2);
+}
+
+#[test]
+fn duplicates_are_merged() {
+    let tess = triangulate(&[0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0]);
+    assert_eq!(tess.vertex_count(), 3);
+    assert_eq!(tess.element_count(), 1);
+    let mut indices = tess.vertex_indices().to_vec();
+    indices.sort();
+    assert_eq!(indices, vec![0, 1, 2]);
+}
+
+#[test]
+fn collinear_points_give_empty_output() {
+    let mut tess = Tessellator::new();
+    assert!(tess.triangulate_points(2, &[0.0, 0.0, 1.0, 1.0, 2.0, 2.0], 2, None));
+    assert_eq!(tess.element_count(), 0);
+    assert_eq!(tess.vertex_count(), 0);
+}
+
+#[test]
+fn terrain_keeps_heights_and_ignores_pending_contours() {
+    let mut tess = Tessellator::new();
+    tess.add_contour(2, &[50.0, 50.0, 60.0, 50.0, 60.0, 60.0]);
+    let points = [0.0, 0.0, 1.0, 10.0, 0.0, 2.0, 10.0, 10.0, 3.0, 0.0, 10.0, 4.0, 5.0, 4.0, 9.0];
+    assert!(tess.triangulate_points(3, &points, 3, None));
+    assert_eq!(tess.vertex_count(), 5);
+    assert_eq!(tess.element_count(), 4);
+    for (k, &idx) in tess.vertex_indices().iter().enumerate() {
+        let idx = idx as usize;
+        assert_eq!(&tess.vertices()[k * 3..k * 3 + 3], &points[idx * 3..idx * 3 + 3]);
+    }
+}
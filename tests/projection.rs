@@ -0,0 +1,86 @@
+// Copyright 2025 Lars Brubaker
+// Tests for the exposed projection: normal, s/t basis, bounds, projected vertices.
+
+use tess2_rust::{ElementType, Tessellator, UvMapping, WindingRule};
+
+fn run(size: usize, contour: &[f64], normal: Option<[f64; 3]>) -> Tessellator {
+    let mut tess = Tessellator::new();
+    tess.add_contour(size, contour);
+    assert!(tess.tessellate(WindingRule::Odd, ElementType::Polygons, 3, size, normal));
+    tess
+}
+
+#[test]
+fn tilted_face_reports_its_computed_plane() {
+    // A square on the plane z = x / 2, counter-clockwise seen from above.
+    let face = [0.0, 0.0, 0.0, 2.0, 0.0, 1.0, 2.0, 2.0, 1.0, 0.0, 2.0, 0.0];
+    let tess = run(3, &face, None);
+    let n = tess.normal();
+    let expected = [-1.0 / 5f64.sqrt(), 0.0, 2.0 / 5f64.sqrt()];
+    for k in 0..3 {
+        assert!((n[k] - expected[k]).abs() < 1e-12, "{:?}", n);
+    }
+    assert_eq!(tess.projection_basis(), ([1.0, 0.0, 0.0], [0.0, 1.0, 0.0]));
+    assert_eq!(tess.projected_bounds(), ([0.0, 0.0], [2.0, 2.0]));
+
+    let st = tess.projected_vertices();
+    assert_eq!(st.len(), tess.vertex_count() * 2);
+    for v in 0..tess.vertex_count() {
+        assert_eq!(st[v * 2..v * 2 + 2], tess.vertices()[v * 3..v * 3 + 2]);
+    }
+}
+
+#[test]
+fn given_normal_picks_the_basis() {
+    // A face in the y-z plane, seen from -x.
+    let face = [0.0, 0.0, 0.0, 0.0, 0.0, 3.0, 0.0, 2.0, 3.0, 0.0, 2.0, 0.0];
+    let tess = run(3, &face, Some([-2.0, 0.0, 0.0]));
+    assert_eq!(tess.normal(), [-1.0, 0.0, 0.0]);
+    let (s, t) = tess.projection_basis();
+    assert_eq!((s, t), ([0.0, 1.0, 0.0], [0.0, 0.0, -1.0]));
+    assert_eq!(tess.projected_bounds(), ([0.0, -3.0], [2.0, 0.0]));
+    let st = tess.projected_vertices();
+    for v in 0..tess.vertex_count() {
+        let p = &tess.vertices()[v * 3..v * 3 + 3];
+        assert_eq!(st[v * 2..v * 2 + 2], [p[1], -p[2]]);
+    }
+}
+
+#[test]
+fn clockwise_input_mirrors_t_but_not_the_normal() {
+    let cw = [0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0, 0.0];
+    let tess = run(2, &cw, None);
+    assert_eq!(tess.normal(), [0.0, 0.0, 1.0]);
+    assert_eq!(tess.projection_basis(), ([1.0, 0.0, 0.0], [0.0, -1.0, 0.0]));
+    assert_eq!(tess.projected_bounds(), ([0.0, -1.0], [1.0, 0.0]));
+}
+
+#[test]
+fn projected_vertices_match_projected_uvs() {
+    let mut tess = Tessellator::new();
+    tess.set_uv_mapping(Some(UvMapping::Projected));
+    tess.add_contour(3, &[0.0, 0.0, 1.0, 4.0, 0.0, 2.0, 4.0, 3.0, 0.5, 0.0, 3.0, 1.5]);
+    assert!(tess.tessellate(WindingRule::Odd, ElementType::ConnectedPolygons, 3, 3, None));
+    assert_eq!(tess.projected_vertices(), tess.uvs());
+
+    let empty = Tessellator::new();
+    assert_eq!(empty.normal(), [0.0; 3]);
+    assert!(empty.projected_vertices().is_empty());
+}
+
+#[test]
+fn projected_vertices_of_3d_input_written_as_2d() {
+    // A face in the y-z plane: dropping z from the output must not change
+    // its projection.
+    let face = [0.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 2.0, 3.0, 0.0, 0.0, 3.0];
+    let full = run(3, &face, None);
+    let mut flat = Tessellator::new();
+    flat.add_contour(3, &face);
+    assert!(flat.tessellate(WindingRule::Odd, ElementType::Polygons, 3, 2, None));
+    assert_eq!(flat.projected_vertices(), full.projected_vertices());
+    let st = flat.projected_vertices();
+    for v in 0..full.vertex_count() {
+        let p = &full.vertices()[v * 3..v * 3 + 3];
+        assert_eq!(st[v * 2..v * 2 + 2], [p[1], p[2]]);
+    }
+}
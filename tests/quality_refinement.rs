@@ -0,0 +1,110 @@
+// Copyright 2025 Lars Brubaker
+// Tests for Ruppert/Chew quality refinement (set_min_angle / set_max_area).
+
+mod helpers;
+
+use tess2_rust::tess::TESS_UNDEF;
+use tess2_rust::{ElementType, Tessellator, WindingRule};
+
+fn refined(contours: &[Vec<f64>], min_angle: f64, max_area: f64) -> Tessellator {
+    let mut tess = Tessellator::new();
+    tess.set_min_angle(min_angle);
+    tess.set_max_area(max_area);
+    for c in contours {
+        tess.add_contour(2, c);
+    }
+    assert!(tess.tessellate(WindingRule::Odd, ElementType::Polygons, 3, 2, None));
+    tess
+}
+
+fn corners(tess: &Tessellator, tri: usize) -> [[f64; 2]; 3] {
+    let v = tess.vertices();
+    let e = &tess.elements()[tri * 3..tri * 3 + 3];
+    [0, 1, 2].map(|i| [v[e[i] as usize * 2], v[e[i] as usize * 2 + 1]])
+}
+
+fn min_angle_degrees(p: [[f64; 2]; 3]) -> f64 {
+    (0..3)
+        .map(|i| {
+            let (a, b, c) = (p[i], p[(i + 1) % 3], p[(i + 2) % 3]);
+            let (u, v) = ([b[0] - a[0], b[1] - a[1]], [c[0] - a[0], c[1] - a[1]]);
+            let cos = (u[0] * v[0] + u[1] * v[1]) / (u[0].hypot(u[1]) * v[0].hypot(v[1]));
+            cos.clamp(-1.0, 1.0).acos().to_degrees()
+        })
+        .fold(180.0, f64::min)
+}
+
+fn flagged_length(tess: &Tessellator) -> f64 {
+    let mut total = 0.0;
+    for tri in 0..tess.element_count() {
+        let p = corners(tess, tri);
+        for i in 0..3 {
+            if tess.edge_flags()[tri * 3 + i] == 1 {
+                let (a, b) = (p[i], p[(i + 1) % 3]);
+                total += (b[0] - a[0]).hypot(b[1] - a[1]);
+            }
+        }
+    }
+    total
+}
+
+#[test]
+fn thin_rectangle_meets_angle_bound() {
+    let plain = refined(&[vec![0.0, 0.0, 100.0, 0.0, 100.0, 1.0, 0.0, 1.0]], 0.0, 0.0);
+    assert_eq!(plain.element_count(), 2);
+
+    let tess = refined(&[vec![0.0, 0.0, 100.0, 0.0, 100.0, 1.0, 0.0, 1.0]], 25.0, 0.0);
+    helpers::verify_valid_output(&tess);
+    assert!(tess.element_count() > 2);
+    for tri in 0..tess.element_count() {
+        let angle = min_angle_degrees(corners(&tess, tri));
+        assert!(angle >= 25.0 - 1e-6, "triangle {} has angle {}", tri, angle);
+    }
+    assert!((helpers::total_tessellation_area(&tess) - 100.0).abs() < 1e-6);
+}
+
+#[test]
+fn max_area_bounds_every_triangle() {
+    let tess = refined(&[vec![0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0]], 0.0, 1.0);
+    helpers::verify_valid_output(&tess);
+    assert!(tess.element_count() >= 100);
+    for tri in 0..tess.element_count() {
+        let [a, b, c] = corners(&tess, tri);
+        let area = helpers::triangle_area(a[0], a[1], b[0], b[1], c[0], c[1]);
+        assert!(area > 0.0 && area <= 1.0 + 1e-9, "triangle {} has area {}", tri, area);
+    }
+    assert!((helpers::total_tessellation_area(&tess) - 100.0).abs() < 1e-6);
+}
+
+#[test]
+fn steiner_points_have_no_input_index() {
+    let tess = refined(&[vec![0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0]], 0.0, 2.0);
+    let indices = tess.vertex_indices();
+    assert_eq!(indices.iter().filter(|&&i| i != TESS_UNDEF).count(), 4);
+    assert!(indices.contains(&TESS_UNDEF));
+}
+
+#[test]
+fn refinement_keeps_holes_and_edge_flags() {
+    let outer = vec![0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0];
+    let hole = vec![3.0, 3.0, 3.0, 7.0, 7.0, 7.0, 7.0, 3.0];
+    let tess = refined(&[outer, hole], 30.0, 2.0);
+    helpers::verify_valid_output(&tess);
+    assert!((helpers::total_tessellation_area(&tess) - 84.0).abs() < 1e-6);
+    // Boundary edges may be subdivided but still add up to both perimeters.
+    assert!((flagged_length(&tess) - 56.0).abs() < 1e-6);
+}
+
+#[test]
+fn steiner_points_stay_on_the_input_plane() {
+    // Square in the plane z = x.
+    let contour = [0.0, 0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 4.0, 4.0, 0.0, 4.0, 0.0];
+    let mut tess = Tessellator::new();
+    tess.set_max_area(0.5);
+    tess.add_contour(3, &contour);
+    assert!(tess.tessellate(WindingRule::Odd, ElementType::Polygons, 3, 3, None));
+    assert!(tess.vertex_count() > 4);
+    for v in tess.vertices().chunks(3) {
+        assert!((v[2] - v[0]).abs() < 1e-9, "vertex {:?} left the plane", v);
+    }
+}
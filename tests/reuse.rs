@@ -0,0 +1,129 @@
+// Copyright 2025 Lars Brubaker
+// Tests for reusing a Tessellator (reset, pooled mesh arenas, retained buffers).
+
+mod helpers;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use tess2_rust::{ElementType, TessOption, Tessellator, WindingRule};
+
+/// Counts allocations made on the current thread, so tests running in
+/// parallel do not see each other's.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|n| n.set(n.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|n| n.set(n.get() + 1));
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(|n| n.get())
+}
+
+/// A frame's worth of small paths: a square with a hole, a self-crossing
+/// bow tie and a star.
+fn frame_paths() -> Vec<Vec<f64>> {
+    let star: Vec<f64> = (0..10)
+        .flat_map(|i| {
+            let a = i as f64 * std::f64::consts::PI / 5.0;
+            let r = if i % 2 == 0 { 3.0 } else { 1.2 };
+            [r * a.cos() + 10.0, r * a.sin()]
+        })
+        .collect();
+    vec![
+        vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0, 1.0, 1.0, 1.0, 3.0, 3.0, 3.0, 3.0, 1.0],
+        vec![5.0, 0.0, 7.0, 2.0, 7.0, 0.0, 5.0, 2.0],
+        star,
+    ]
+}
+
+fn draw_frame(tess: &mut Tessellator, paths: &[Vec<f64>], element_type: ElementType, poly_size: usize) -> usize {
+    let mut elements = 0;
+    for path in paths {
+        tess.add_contour(2, path);
+        assert!(tess.tessellate(WindingRule::Odd, element_type, poly_size, 2, None));
+        elements += tess.element_count();
+    }
+    elements
+}
+
+#[test]
+fn warmed_up_tessellator_does_not_allocate() {
+    let paths = frame_paths();
+    let modes = [
+        (ElementType::Polygons, 3, false),
+        (ElementType::Polygons, 6, false),
+        (ElementType::ConnectedPolygons, 3, false),
+        (ElementType::BoundaryContours, 0, false),
+        (ElementType::EdgeList, 3, false),
+        (ElementType::ConvexPartition, 0, false),
+        (ElementType::Polygons, 3, true),
+        (ElementType::ConvexPartition, 0, true),
+    ];
+    for (element_type, poly_size, voronoi) in modes {
+        let mut tess = Tessellator::new();
+        tess.set_option(TessOption::Voronoi, voronoi);
+        let expected = draw_frame(&mut tess, &paths, element_type, poly_size);
+        draw_frame(&mut tess, &paths, element_type, poly_size);
+
+        let before = allocations();
+        for _ in 0..20 {
+            tess.reset();
+            assert_eq!(draw_frame(&mut tess, &paths, element_type, poly_size), expected);
+        }
+        let allocated = allocations() - before;
+        assert_eq!(allocated, 0, "{:?} with poly_size {}, voronoi {}", element_type, poly_size, voronoi);
+        assert_eq!(tess.voronoi_cells().is_empty(), !voronoi);
+    }
+}
+
+#[test]
+fn reused_output_matches_fresh_tessellator() {
+    let paths = frame_paths();
+    let mut reused = Tessellator::new();
+    draw_frame(&mut reused, &paths, ElementType::Polygons, 3);
+    for path in paths.iter().rev() {
+        let fresh = helpers::tessellate_contours(std::slice::from_ref(path), WindingRule::Odd);
+        reused.reset();
+        reused.add_contour(2, path);
+        assert!(reused.tessellate(WindingRule::Odd, ElementType::Polygons, 3, 2, None));
+        assert_eq!(reused.vertices(), fresh.vertices());
+        assert_eq!(reused.elements(), fresh.elements());
+        assert_eq!(reused.vertex_indices(), fresh.vertex_indices());
+    }
+}
+
+#[test]
+fn reset_discards_pending_input() {
+    let mut tess = Tessellator::new();
+    tess.add_contour(2, &[0.0, 0.0, 9.0, 0.0, 9.0, 9.0]);
+    tess.reset();
+    tess.add_contour(2, &[0.0, 0.0, 1.0, 0.0, 0.0, 1.0]);
+    assert!(tess.tessellate(WindingRule::Odd, ElementType::Polygons, 3, 2, None));
+    assert_eq!(tess.element_count(), 1);
+    let mut indices = tess.vertex_indices().to_vec();
+    indices.sort_unstable();
+    assert_eq!(indices, [0, 1, 2]);
+    assert!((helpers::total_tessellation_area(&tess) - 0.5).abs() < 1e-12);
+
+    tess.reset();
+    assert_eq!(tess.element_count(), 0);
+    assert!(tess.vertices().is_empty());
+}
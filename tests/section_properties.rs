@@ -0,0 +1,135 @@
+// Copyright 2025 Lars Brubaker
+// Tests for the section properties (area, centroid, second moments, bounds).
+
+mod helpers;
+
+use tess2_rust::section::{Section, SectionProperties};
+use tess2_rust::{ElementType, Tessellator, WindingRule};
+
+fn section(contours: &[Vec<f64>], element_type: ElementType, poly_size: usize) -> Section {
+    let mut tess = Tessellator::new();
+    for c in contours {
+        tess.add_contour(2, c);
+    }
+    assert!(tess.tessellate(WindingRule::Odd, element_type, poly_size, 2, None));
+    Section::new(tess.vertices(), 2, tess.elements(), element_type, poly_size)
+}
+
+fn assert_close(a: &SectionProperties, b: &SectionProperties) {
+    let close = |x: f64, y: f64| (x - y).abs() <= 1e-9 * (1.0 + x.abs().max(y.abs()));
+    assert!(close(a.area, b.area), "{:?} vs {:?}", a, b);
+    for k in 0..2 {
+        assert!(close(a.centroid[k], b.centroid[k]), "{:?} vs {:?}", a, b);
+        assert_eq!((a.min[k], a.max[k]), (b.min[k], b.max[k]));
+    }
+    for k in 0..3 {
+        assert!(close(a.second_moments[k], b.second_moments[k]), "{:?} vs {:?}", a, b);
+    }
+}
+
+/// A 6×4 rectangle with a 2×2 hole, far from the origin.
+fn plate() -> Vec<Vec<f64>> {
+    let (x, y) = (1.0e6, -2.0e6);
+    vec![
+        vec![x, y, x + 6.0, y, x + 6.0, y + 4.0, x, y + 4.0],
+        vec![x + 1.0, y + 1.0, x + 1.0, y + 3.0, x + 3.0, y + 3.0, x + 3.0, y + 1.0],
+    ]
+}
+
+#[test]
+fn plate_with_hole_matches_closed_form() {
+    let (x, y) = (1.0e6, -2.0e6);
+    // Rectangle minus hole, each about its own centroid, moved to the
+    // combined centroid.
+    let area = 24.0 - 4.0;
+    let cx = (24.0 * 3.0 - 4.0 * 2.0) / area;
+    let cy = (24.0 * 2.0 - 4.0 * 2.0) / area;
+    let ixx = (6.0 * 64.0 / 12.0 + 24.0 * (2.0 - cy) * (2.0 - cy)) - (2.0 * 8.0 / 12.0 + 4.0 * (2.0 - cy) * (2.0 - cy));
+    let iyy = (4.0 * 216.0 / 12.0 + 24.0 * (3.0 - cx) * (3.0 - cx)) - (2.0 * 8.0 / 12.0 + 4.0 * (2.0 - cx) * (2.0 - cx));
+    let ixy = 24.0 * (3.0 - cx) * (2.0 - cy) - 4.0 * (2.0 - cx) * (2.0 - cy);
+    let expected = SectionProperties {
+        area,
+        centroid: [x + cx, y + cy],
+        second_moments: [ixx, iyy, ixy],
+        min: [x, y],
+        max: [x + 6.0, y + 4.0],
+    };
+    for (element_type, poly_size) in [
+        (ElementType::Polygons, 3),
+        (ElementType::ConnectedPolygons, 3),
+        (ElementType::ConnectedPolygons, 6),
+        (ElementType::ConvexPartition, 0),
+    ] {
+        let s = section(&plate(), element_type, poly_size);
+        assert_close(&s.total(), &expected);
+        assert_eq!(s.islands().len(), 1);
+        assert_close(&s.islands()[0], &expected);
+        let sum: f64 = s.polygons().iter().map(|p| p.area).sum();
+        assert!((sum - area).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn islands_are_split_and_sum_to_the_total() {
+    let contours = vec![
+        vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0],
+        vec![10.0, 0.0, 12.0, 0.0, 11.0, 3.0],
+        // Touches the first square at a corner only.
+        vec![4.0, 4.0, 6.0, 4.0, 6.0, 6.0, 4.0, 6.0],
+    ];
+    let s = section(&contours, ElementType::ConnectedPolygons, 3);
+    assert_eq!(s.islands().len(), 3);
+    let mut areas: Vec<f64> = s.islands().iter().map(|i| i.area).collect();
+    areas.sort_by(f64::total_cmp);
+    assert_eq!(areas, vec![3.0, 4.0, 16.0]);
+    for (p, props) in s.polygons().iter().enumerate() {
+        let island = &s.islands()[s.island_of(p)];
+        assert!(props.min[0] >= island.min[0] && props.max[0] <= island.max[0]);
+    }
+
+    let total = s.total();
+    assert_eq!(total.area, 23.0);
+    assert_eq!((total.min, total.max), ([0.0, 0.0], [12.0, 6.0]));
+    // Moments about the origin add up over the islands.
+    let about: [f64; 3] = s.islands().iter().fold([0.0; 3], |acc, i| {
+        let m = i.moments_about([0.0, 0.0]);
+        [acc[0] + m[0], acc[1] + m[1], acc[2] + m[2]]
+    });
+    let whole = total.moments_about([0.0, 0.0]);
+    for k in 0..3 {
+        assert!((about[k] - whole[k]).abs() < 1e-9 * whole[k].abs().max(1.0));
+    }
+}
+
+#[test]
+fn area_matches_the_triangle_sum_on_test_data() {
+    for (name, data) in [
+        ("dude", include_str!("data/dude.dat")),
+        ("funny", include_str!("data/funny.dat")),
+        ("kzer_za", include_str!("data/kzer_za.dat")),
+    ] {
+        let contours = helpers::parse_contours(data);
+        let tess = helpers::tessellate_contours(&contours, WindingRule::Odd);
+        let s = Section::new(tess.vertices(), 2, tess.elements(), ElementType::Polygons, 3);
+        let expected = helpers::total_tessellation_area(&tess);
+        assert!((s.total().area - expected).abs() <= 1e-9 * expected, "{}", name);
+        assert_eq!(s.polygons().len(), tess.element_count());
+    }
+}
+
+#[test]
+fn orientation_and_empty_output_do_not_matter() {
+    let square = vec![vec![0.0, 0.0, 2.0, 0.0, 2.0, 2.0, 0.0, 2.0]];
+    let mut tess = Tessellator::new();
+    tess.add_contour(2, &square[0]);
+    assert!(tess.tessellate(WindingRule::Odd, ElementType::Polygons, 3, 2, Some([0.0, 0.0, -1.0])));
+    let flipped = Section::new(tess.vertices(), 2, tess.elements(), ElementType::Polygons, 3);
+    let plain = section(&square, ElementType::Polygons, 3);
+    assert_close(&flipped.total(), &plain.total());
+    assert_eq!(plain.total().centroid, [1.0, 1.0]);
+    assert!((plain.total().polar_moment() - 2.0 * 16.0 / 12.0).abs() < 1e-12);
+
+    let empty = Section::new(&[], 2, &[], ElementType::Polygons, 3);
+    assert_eq!(empty.total(), SectionProperties::default());
+    assert!(empty.islands().is_empty());
+}
@@ -0,0 +1,133 @@
+// Copyright 2025 Lars Brubaker
+// Tests for snap rounding onto a grid (set_snap_grid).
+
+mod helpers;
+
+use tess2_rust::{ElementType, TessOption, TessStatus, Tessellator, WindingRule};
+
+fn snapped(contours: &[Vec<f64>], grid: f64, winding_rule: WindingRule) -> Tessellator {
+    let mut tess = Tessellator::new();
+    tess.set_snap_grid(grid);
+    for c in contours {
+        tess.add_contour(2, c);
+    }
+    assert!(tess.tessellate(winding_rule, ElementType::Polygons, 3, 2, None));
+    helpers::verify_valid_output(&tess);
+    tess
+}
+
+/// Every vertex on the grid, and no triangle wound against the others.
+fn assert_snapped(tess: &Tessellator, grid: f64) {
+    for &x in tess.vertices() {
+        let k = x / grid;
+        assert_eq!(k, k.round(), "{} is off the {} grid", x, grid);
+    }
+    let verts = tess.vertices();
+    let sign = helpers::total_tessellation_signed_area(tess).signum();
+    for tri in tess.elements().chunks(3) {
+        let p = |i: u32| (verts[i as usize * 2], verts[i as usize * 2 + 1]);
+        let ((x0, y0), (x1, y1), (x2, y2)) = (p(tri[0]), p(tri[1]), p(tri[2]));
+        let area = helpers::triangle_area(x0, y0, x1, y1, x2, y2);
+        assert!(area * sign >= 0.0, "folded triangle {:?}", tri);
+    }
+}
+
+/// A star with `n` spikes, rotated and scaled off the grid.
+fn star(n: usize, angle: f64, radius: f64) -> Vec<f64> {
+    (0..2 * n)
+        .flat_map(|i| {
+            let a = angle + i as f64 * std::f64::consts::PI / n as f64;
+            let r = if i % 2 == 0 { radius } else { radius * 0.37 };
+            [r * a.cos() + 0.31, r * a.sin() - 0.17]
+        })
+        .collect()
+}
+
+#[test]
+fn intersections_land_on_grid() {
+    let contours = vec![
+        vec![0.2, 0.3, 10.7, 0.1, 10.4, 10.9, 0.6, 10.2],
+        vec![5.1, -3.3, 14.2, 5.4, 5.3, 14.6, -3.8, 5.2],
+    ];
+    let tess = snapped(&contours, 1.0, WindingRule::NonZero);
+    assert_snapped(&tess, 1.0);
+    let area = helpers::total_tessellation_area(&tess);
+    assert!(area > 140.0 && area < 170.0, "area {}", area);
+}
+
+#[test]
+fn edge_passing_near_a_vertex_is_rerouted() {
+    // The notch tip sits just above the sloped top edge.  Rounding only the
+    // vertices would put the tip at (5, 4), under the rounded edge
+    // (0, 4)-(10, 5), and the two pieces would overlap.  Snap rounding bends
+    // the edge through the tip's pixel instead, so they only touch.
+    let contours = vec![
+        vec![0.0, 0.0, 10.0, 0.0, 10.0, 4.55, 0.0, 4.35],
+        vec![2.0, 8.0, 5.4, 4.48, 8.0, 8.0],
+    ];
+    let tess = snapped(&contours, 1.0, WindingRule::Odd);
+    assert_snapped(&tess, 1.0);
+    // 42.5 for the rerouted rectangle plus 12 for the notch.
+    let area = helpers::total_tessellation_area(&tess);
+    assert!((area - 54.5).abs() < 1e-9, "area {}", area);
+}
+
+#[test]
+fn rotated_stars_stay_unfolded() {
+    for step in 0..40 {
+        let angle = step as f64 * 0.157;
+        let contours = vec![star(7, angle, 20.0), star(5, -angle, 13.0)];
+        for grid in [1.0, 0.5, 2.0] {
+            let tess = snapped(&contours, grid, WindingRule::Odd);
+            assert_snapped(&tess, grid);
+        }
+    }
+}
+
+#[test]
+fn zero_grid_leaves_output_untouched() {
+    let contours = vec![star(5, 0.3, 4.0)];
+    let plain = helpers::tessellate_contours(&contours, WindingRule::NonZero);
+    let tess = snapped(&contours, 0.0, WindingRule::NonZero);
+    assert_eq!(tess.vertices(), plain.vertices());
+    assert_eq!(tess.elements(), plain.elements());
+}
+
+#[test]
+fn snapping_needs_the_xy_plane() {
+    let run = |contour: &[f64], orthonormal: bool| {
+        let mut tess = Tessellator::new();
+        tess.set_snap_grid(1.0);
+        tess.set_option(TessOption::OrthonormalProjection, orthonormal);
+        tess.add_contour(3, contour);
+        let ok = tess.tessellate(WindingRule::Odd, ElementType::Polygons, 3, 3, None);
+        (ok, tess)
+    };
+
+    // A gently tilted face still sweeps in x and y: both land on the grid.
+    let tilted = [0.2, 0.3, 0.0, 10.7, 0.1, 1.3, 10.4, 10.9, 2.9, 0.6, 10.2, 1.1];
+    let (ok, tess) = run(&tilted, false);
+    assert!(ok);
+    for v in tess.vertices().chunks(3) {
+        assert_eq!([v[0], v[1]], [v[0].round(), v[1].round()]);
+    }
+
+    // Sweeping in another plane would leave x and y off the grid.
+    let (ok, tess) = run(&tilted, true);
+    assert!(!ok);
+    assert_eq!(tess.get_status(), TessStatus::InvalidInput);
+    let upright = [0.2, 0.0, 0.3, 10.7, 0.0, 0.1, 10.4, 0.0, 10.9, 0.6, 0.0, 10.2];
+    let (ok, tess) = run(&upright, false);
+    assert!(!ok);
+    assert_eq!(tess.get_status(), TessStatus::InvalidInput);
+}
+
+#[test]
+fn crossings_found_by_the_sweep_stay_unfolded() {
+    // The rerouted edges still cross between grid points here; the sweep
+    // puts that crossing at its pixel centre rather than leaving it to be
+    // rounded afterwards.
+    let contours = vec![vec![2.4, 16.9, 2.7, 8.7, 18.4, 11.0], vec![4.7, 15.6, 19.5, 12.0, 8.5, 14.6]];
+    let tess = snapped(&contours, 1.0, WindingRule::Odd);
+    assert_snapped(&tess, 1.0);
+}
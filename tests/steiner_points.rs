@@ -0,0 +1,110 @@
+// Copyright 2025 Lars Brubaker
+// Tests for caller-supplied interior points (add_points).
+
+mod helpers;
+
+use tess2_rust::{ElementType, TessOption, TessStatus, Tessellator, WindingRule};
+
+const SQUARE: [f64; 8] = [0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 0.0, 10.0];
+
+fn tessellate(tess: &mut Tessellator) {
+    assert!(tess.tessellate(WindingRule::Odd, ElementType::Polygons, 3, 2, None));
+    helpers::verify_valid_output(tess);
+}
+
+/// Output position of the vertex reporting input index `idx`.
+fn find_index(tess: &Tessellator, idx: u32) -> Option<[f64; 2]> {
+    let i = tess.vertex_indices().iter().position(|&v| v == idx)?;
+    Some([tess.vertices()[i * 2], tess.vertices()[i * 2 + 1]])
+}
+
+#[test]
+fn interior_point_becomes_a_vertex() {
+    let mut tess = Tessellator::new();
+    tess.add_contour(2, &SQUARE);
+    tess.add_points(2, &[4.0, 6.0]);
+    tessellate(&mut tess);
+    assert_eq!(tess.vertex_count(), 5);
+    assert_eq!(tess.element_count(), 4);
+    assert_eq!(find_index(&tess, 4), Some([4.0, 6.0]));
+    assert!((helpers::total_tessellation_area(&tess) - 100.0).abs() < 1e-9);
+}
+
+#[test]
+fn points_outside_the_fill_are_dropped() {
+    let mut tess = Tessellator::new();
+    tess.add_contour(2, &SQUARE);
+    tess.add_contour(2, &[3.0, 3.0, 3.0, 7.0, 7.0, 7.0, 7.0, 3.0]);
+    // Inside the hole, outside the square, on a corner, then a real one.
+    tess.add_points(2, &[5.0, 5.0, 20.0, 5.0, 0.0, 0.0, 1.0, 5.0]);
+    tessellate(&mut tess);
+    assert_eq!(tess.vertex_count(), 9);
+    assert_eq!(find_index(&tess, 8), None);
+    assert_eq!(find_index(&tess, 9), None);
+    assert_eq!(find_index(&tess, 10), None);
+    assert_eq!(find_index(&tess, 11), Some([1.0, 5.0]));
+    assert_eq!(tess.out_dropped_points, 3);
+    assert!((helpers::total_tessellation_area(&tess) - 84.0).abs() < 1e-9);
+}
+
+#[test]
+fn failed_run_drops_its_points() {
+    let mut tess = Tessellator::new();
+    tess.set_planarity_tolerance(0.1);
+    tess.add_contour(3, &[0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 10.0, 5.0, 0.0, 10.0, 0.0]);
+    tess.add_points(3, &[4.0, 6.0, 2.0]);
+    assert!(!tess.tessellate(WindingRule::Odd, ElementType::Polygons, 3, 3, None));
+    assert_eq!(tess.get_status(), TessStatus::NonPlanar);
+
+    // Retrying the same contour without the check leaves the point out.
+    tess.status = TessStatus::Ok;
+    tess.set_planarity_tolerance(0.0);
+    assert!(tess.tessellate(WindingRule::Odd, ElementType::Polygons, 3, 3, None));
+    assert_eq!(tess.vertex_count(), 4);
+    assert_eq!(tess.out_dropped_points, 0);
+}
+
+#[test]
+fn point_on_boundary_splits_the_edge() {
+    let mut tess = Tessellator::new();
+    tess.add_contour(2, &SQUARE);
+    tess.add_points(2, &[5.0, 0.0]);
+    tessellate(&mut tess);
+    assert_eq!(find_index(&tess, 4), Some([5.0, 0.0]));
+    let boundary = tess.edge_flags().iter().filter(|&&f| f == 1).count();
+    assert_eq!(boundary, 5);
+}
+
+#[test]
+fn many_points_with_cdt() {
+    let mut points = Vec::new();
+    for i in 1..10 {
+        for j in 1..10 {
+            points.push(i as f64 + 0.1 * j as f64);
+            points.push(j as f64 + 0.05 * i as f64);
+        }
+    }
+    let mut tess = Tessellator::new();
+    tess.set_option(TessOption::ConstrainedDelaunayTriangulation, true);
+    tess.add_contour(2, &SQUARE);
+    tess.add_points(2, &points);
+    tessellate(&mut tess);
+    assert_eq!(tess.vertex_count(), 4 + 81);
+    // Every triangle of a Delaunay triangulation of a convex point set is
+    // counted once by Euler: 2n - h - 2 with h = 4 hull vertices.
+    assert_eq!(tess.element_count(), 2 * 85 - 4 - 2);
+    for idx in 4..85 {
+        assert!(find_index(&tess, idx).is_some(), "point {} missing", idx);
+    }
+    assert!((helpers::total_tessellation_area(&tess) - 100.0).abs() < 1e-9);
+}
+
+#[test]
+fn points_keep_their_3d_coordinates() {
+    let mut tess = Tessellator::new();
+    tess.add_contour(3, &[0.0, 0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 4.0, 4.0, 0.0, 4.0, 0.0]);
+    tess.add_points(3, &[1.0, 2.0, 1.0]);
+    assert!(tess.tessellate(WindingRule::Odd, ElementType::Polygons, 3, 3, None));
+    let i = tess.vertex_indices().iter().position(|&v| v == 4).unwrap();
+    assert_eq!(&tess.vertices()[i * 3..i * 3 + 3], &[1.0, 2.0, 1.0]);
+}
@@ -0,0 +1,119 @@
+// Copyright 2025 Lars Brubaker
+// Tests for planar texture coordinate output (set_uv_mapping).
+
+use tess2_rust::{ElementType, Tessellator, UvMapping, WindingRule};
+
+fn run(contours: &[Vec<f64>], size: usize, mapping: Option<UvMapping>, normal: Option<[f64; 3]>) -> Tessellator {
+    let mut tess = Tessellator::new();
+    tess.set_uv_mapping(mapping);
+    for c in contours {
+        tess.add_contour(size, c);
+    }
+    assert!(tess.tessellate(WindingRule::Odd, ElementType::Polygons, 3, size, normal));
+    tess
+}
+
+fn uv(tess: &Tessellator, v: usize) -> [f64; 2] {
+    [tess.uvs()[v * 2], tess.uvs()[v * 2 + 1]]
+}
+
+#[test]
+fn uvs_are_off_by_default() {
+    let tess = run(&[vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0]], 2, None, None);
+    assert!(tess.uvs().is_empty());
+}
+
+#[test]
+fn projected_uvs_follow_the_projection_axes() {
+    let square = vec![vec![2.0, 3.0, 6.0, 3.0, 6.0, 5.0, 2.0, 5.0]];
+    let tess = run(&square, 2, Some(UvMapping::Projected), None);
+    assert_eq!(tess.uvs().len(), tess.vertex_count() * 2);
+    for v in 0..tess.vertex_count() {
+        assert_eq!(uv(&tess, v), [tess.vertices()[v * 2], tess.vertices()[v * 2 + 1]]);
+    }
+
+    // A face in the x-z plane, seen from +y: s runs along z and t along x.
+    let face = vec![vec![0.0, 1.0, 0.0, 0.0, 1.0, 2.0, 3.0, 1.0, 2.0, 3.0, 1.0, 0.0]];
+    let tess = run(&face, 3, Some(UvMapping::Projected), Some([0.0, 1.0, 0.0]));
+    for v in 0..tess.vertex_count() {
+        let p = &tess.vertices()[v * 3..v * 3 + 3];
+        assert_eq!(uv(&tess, v), [p[2], p[0]]);
+    }
+}
+
+#[test]
+fn normalized_uvs_span_the_unit_square() {
+    let ccw = vec![2.0, 3.0, 6.0, 3.0, 6.0, 5.0, 2.0, 5.0];
+    let cw = vec![2.0, 3.0, 2.0, 5.0, 6.0, 5.0, 6.0, 3.0];
+    for contour in [ccw, cw] {
+        let tess = run(&[contour], 2, Some(UvMapping::Normalized), None);
+        let mut corners: Vec<[f64; 2]> = (0..tess.vertex_count()).map(|v| uv(&tess, v)).collect();
+        corners.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(corners, vec![[0.0, 0.0], [0.0, 1.0], [1.0, 0.0], [1.0, 1.0]]);
+    }
+
+    // A fixed normal keeps t from being mirrored for clockwise input.
+    let cw = vec![vec![2.0, 3.0, 2.0, 5.0, 6.0, 5.0, 6.0, 3.0]];
+    let tess = run(&cw, 2, Some(UvMapping::Normalized), Some([0.0, 0.0, 1.0]));
+    for v in 0..tess.vertex_count() {
+        let (x, y) = (tess.vertices()[v * 2], tess.vertices()[v * 2 + 1]);
+        assert_eq!(uv(&tess, v), [(x - 2.0) / 4.0, (y - 3.0) / 2.0]);
+    }
+}
+
+#[test]
+fn affine_uvs_apply_the_transform() {
+    let triangle = vec![vec![0.0, 0.0, 4.0, 0.0, 0.0, 2.0]];
+    let m = [0.5, 0.25, 1.0, -0.25, 2.0, -3.0];
+    let tess = run(&triangle, 2, Some(UvMapping::Affine(m)), None);
+    for v in 0..tess.vertex_count() {
+        let (s, t) = (tess.vertices()[v * 2], tess.vertices()[v * 2 + 1]);
+        assert_eq!(uv(&tess, v), [m[0] * s + m[1] * t + m[2], m[3] * s + m[4] * t + m[5]]);
+    }
+}
+
+#[test]
+fn retessellate_and_point_clouds_write_uvs() {
+    let contours = [
+        vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0],
+        vec![10.0, 1.0, 12.0, 1.0, 11.0, 3.0],
+    ];
+    let full = run(&contours, 2, Some(UvMapping::Normalized), None);
+    let uv_at = |tess: &Tessellator, p: [f64; 2]| {
+        let v = (0..tess.vertex_count()).find(|&v| tess.vertices()[v * 2..v * 2 + 2] == p).unwrap();
+        uv(tess, v)
+    };
+
+    let mut inc = Tessellator::new();
+    inc.set_uv_mapping(Some(UvMapping::Normalized));
+    for c in &contours {
+        inc.insert_contour(2, c);
+    }
+    assert!(inc.retessellate(WindingRule::Odd, ElementType::Polygons, 3, 2, None));
+    assert_eq!(inc.uvs().len(), full.uvs().len());
+    for v in 0..inc.vertex_count() {
+        let p = [inc.vertices()[v * 2], inc.vertices()[v * 2 + 1]];
+        assert_eq!(uv(&inc, v), uv_at(&full, p));
+    }
+
+    let mut cloud = Tessellator::new();
+    cloud.set_uv_mapping(Some(UvMapping::Projected));
+    assert!(cloud.triangulate_points(2, &[0.0, 0.0, 3.0, 0.0, 0.0, 3.0, 1.0, 1.0], 2, Some([0.0, 0.0, 1.0])));
+    assert_eq!(cloud.uvs(), cloud.vertices());
+}
+
+#[test]
+fn uvs_of_3d_input_do_not_depend_on_the_output_vertex_size() {
+    // A square in the x-z plane, written out as 3D and as truncated 2D.
+    let square = [0.0, 1.0, 0.0, 4.0, 1.0, 0.0, 4.0, 1.0, 4.0, 0.0, 1.0, 4.0];
+    let uvs_for = |vertex_size: usize| {
+        let mut tess = Tessellator::new();
+        tess.set_uv_mapping(Some(UvMapping::Projected));
+        tess.add_contour(3, &square);
+        assert!(tess.tessellate(WindingRule::Odd, ElementType::Polygons, 3, vertex_size, None));
+        tess.uvs().to_vec()
+    };
+    let uvs = uvs_for(3);
+    assert_eq!(uvs, [0.0, 0.0, 4.0, -4.0, 4.0, 0.0, 0.0, -4.0]);
+    assert_eq!(uvs_for(2), uvs);
+}
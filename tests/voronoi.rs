@@ -0,0 +1,179 @@
+// Copyright 2025 Lars Brubaker
+// Tests for Voronoi cells of the triangulation (TessOption::Voronoi).
+
+mod helpers;
+
+use tess2_rust::{ElementType, TessOption, Tessellator, WindingRule};
+
+fn voronoi(contours: &[Vec<f64>], points: &[f64]) -> Tessellator {
+    let mut tess = Tessellator::new();
+    tess.set_option(TessOption::Voronoi, true);
+    for c in contours {
+        tess.add_contour(2, c);
+    }
+    if !points.is_empty() {
+        tess.add_points(2, points);
+    }
+    assert!(tess.tessellate(WindingRule::Odd, ElementType::Polygons, 3, 2, None));
+    helpers::verify_valid_output(&tess);
+    tess
+}
+
+fn cell(tess: &Tessellator, i: usize) -> Vec<[f64; 2]> {
+    let c = tess.voronoi_cells()[i];
+    let v = tess.voronoi_vertices();
+    tess.voronoi_indices()[c.start..c.start + c.count]
+        .iter()
+        .map(|&k| [v[k as usize * 2], v[k as usize * 2 + 1]])
+        .collect()
+}
+
+fn site(tess: &Tessellator, i: usize) -> [f64; 2] {
+    let s = tess.voronoi_cells()[i].site as usize;
+    [tess.vertices()[s * 2], tess.vertices()[s * 2 + 1]]
+}
+
+fn area(poly: &[[f64; 2]]) -> f64 {
+    let flat: Vec<f64> = poly.iter().flat_map(|p| [p[0], p[1]]).collect();
+    helpers::polygon_signed_area(&flat)
+}
+
+fn dist2(a: [f64; 2], b: [f64; 2]) -> f64 {
+    (a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)
+}
+
+/// Cells are counter-clockwise, have positive area and add up to the fill.
+fn assert_tiles(tess: &Tessellator, expected: f64) {
+    let mut total = 0.0;
+    for i in 0..tess.voronoi_cells().len() {
+        let a = area(&cell(tess, i));
+        assert!(a > 0.0, "cell {} has area {}", i, a);
+        total += a;
+    }
+    assert!((total - expected).abs() < 1e-6 * expected, "cells cover {} of {}", total, expected);
+}
+
+fn square(x0: f64, y0: f64, x1: f64, y1: f64) -> Vec<f64> {
+    vec![x0, y0, x1, y0, x1, y1, x0, y1]
+}
+
+#[test]
+fn square_with_center_site() {
+    let tess = voronoi(&[square(0.0, 0.0, 4.0, 4.0)], &[2.0, 2.0]);
+    assert_eq!(tess.voronoi_cells().len(), 5);
+    assert_tiles(&tess, 16.0);
+    for i in 0..5 {
+        let expected = if site(&tess, i) == [2.0, 2.0] { 8.0 } else { 2.0 };
+        assert!((area(&cell(&tess, i)) - expected).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn scattered_cells_are_nearest_site_regions() {
+    let mut seed = 11u64;
+    let mut rnd = || {
+        seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (seed >> 11) as f64 / (1u64 << 53) as f64
+    };
+    let points: Vec<f64> = (0..800).map(|_| rnd() * 100.0).collect();
+    let mut tess = Tessellator::new();
+    tess.set_option(TessOption::Voronoi, true);
+    assert!(tess.triangulate_points(2, &points, 2, None));
+    assert_eq!(tess.voronoi_cells().len(), tess.vertex_count());
+    assert_tiles(&tess, helpers::total_tessellation_area(&tess));
+
+    // Away from the hull the cells are exact: every corner is at least as
+    // close to its own site as to any other.
+    let sites: Vec<[f64; 2]> = tess.vertices().chunks(2).map(|v| [v[0], v[1]]).collect();
+    let mut checked = 0;
+    for i in 0..tess.voronoi_cells().len() {
+        let own = site(&tess, i);
+        if own.iter().any(|&x| !(30.0..=70.0).contains(&x)) {
+            continue;
+        }
+        for c in cell(&tess, i) {
+            let d = dist2(c, own);
+            assert!(sites.iter().all(|s| dist2(c, *s) >= d - 1e-6), "corner {:?} of {:?}", c, own);
+        }
+        checked += 1;
+    }
+    assert!(checked > 40);
+}
+
+#[test]
+fn cells_stay_inside_concave_fill() {
+    let l_shape = vec![0.0, 0.0, 10.0, 0.0, 10.0, 2.0, 2.0, 2.0, 2.0, 10.0, 0.0, 10.0];
+    let tess = voronoi(&[l_shape], &[1.0, 1.0, 6.0, 1.0, 1.0, 6.0, 1.5, 1.5]);
+    assert_tiles(&tess, 36.0);
+    for i in 0..tess.voronoi_cells().len() {
+        for c in cell(&tess, i) {
+            let in_l = (0.0..=10.0).contains(&c[0]) && (0.0..=2.0).contains(&c[1])
+                || (0.0..=2.0).contains(&c[0]) && (0.0..=10.0).contains(&c[1]);
+            assert!(in_l, "corner {:?} outside the fill", c);
+        }
+    }
+}
+
+#[test]
+fn constraints_cut_cells() {
+    let mut tess = Tessellator::new();
+    tess.set_option(TessOption::Voronoi, true);
+    tess.add_contour(2, &square(0.0, 0.0, 10.0, 10.0));
+    tess.add_constraint(2, &[5.0, -1.0, 5.0, 11.0]);
+    tess.add_points(2, &[4.0, 5.0, 6.0, 5.0, 2.0, 8.0, 8.0, 2.0]);
+    assert!(tess.tessellate(WindingRule::Odd, ElementType::Polygons, 3, 2, None));
+    assert_tiles(&tess, 100.0);
+    for i in 0..tess.voronoi_cells().len() {
+        let side = site(&tess, i)[0] - 5.0;
+        for c in cell(&tess, i) {
+            assert!((c[0] - 5.0) * side >= -1e-9, "cell of site {:?} crosses the wall", site(&tess, i));
+        }
+    }
+}
+
+#[test]
+fn convex_partition_gets_one_cell_per_vertex() {
+    // A concave pentagon: the partition writes its reflex corner twice.
+    let contour = [0.0, 0.0, 6.0, 0.0, 6.0, 6.0, 3.0, 2.0, 0.0, 6.0];
+    let mut tess = Tessellator::new();
+    tess.set_option(TessOption::Voronoi, true);
+    tess.add_contour(2, &contour);
+    assert!(tess.tessellate(WindingRule::Odd, ElementType::ConvexPartition, 3, 2, None));
+    assert!(tess.element_count() > 1);
+
+    let mut sites: Vec<u32> = tess.voronoi_cells().iter().map(|c| tess.vertex_indices()[c.site as usize]).collect();
+    sites.sort_unstable();
+    assert_eq!(sites, [0, 1, 2, 3, 4]);
+    for c in tess.voronoi_cells() {
+        let first = tess.vertex_indices().iter().position(|&idx| idx == tess.vertex_indices()[c.site as usize]);
+        assert_eq!(first, Some(c.site as usize));
+    }
+    assert_tiles(&tess, 24.0);
+}
+
+#[test]
+fn cells_follow_the_input_plane() {
+    let mut tess = Tessellator::new();
+    tess.set_option(TessOption::Voronoi, true);
+    tess.add_contour(3, &[0.0, 0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 3.0, 4.0, 1.0, 4.0, 1.0]);
+    tess.add_points(3, &[2.0, 2.0, 2.0, 3.0, 1.0, 3.0]);
+    assert!(tess.tessellate(WindingRule::Odd, ElementType::Polygons, 3, 3, None));
+    assert!(!tess.voronoi_cells().is_empty());
+    for v in tess.voronoi_vertices().chunks(3) {
+        assert!((v[2] - v[0]).abs() < 1e-9, "corner {:?} left the plane", v);
+    }
+}
+
+#[test]
+fn off_unless_requested() {
+    let mut tess = Tessellator::new();
+    tess.add_contour(2, &square(0.0, 0.0, 4.0, 4.0));
+    assert!(tess.tessellate(WindingRule::Odd, ElementType::Polygons, 3, 2, None));
+    assert!(tess.voronoi_cells().is_empty());
+
+    tess.set_option(TessOption::Voronoi, true);
+    tess.add_contour(2, &square(0.0, 0.0, 4.0, 4.0));
+    assert!(tess.tessellate(WindingRule::Odd, ElementType::BoundaryContours, 3, 2, None));
+    assert!(tess.voronoi_cells().is_empty());
+}
+
@@ -0,0 +1,83 @@
+// Copyright 2025 Lars Brubaker
+// Tests for tolerance-based vertex welding (set_weld_epsilon).
+
+mod helpers;
+
+use tess2_rust::{ElementType, Tessellator, WindingRule};
+
+fn welded(contours: &[Vec<f64>], epsilon: f64) -> Tessellator {
+    let mut tess = Tessellator::new();
+    tess.set_weld_epsilon(epsilon);
+    for c in contours {
+        tess.add_contour(2, c);
+    }
+    assert!(tess.tessellate(WindingRule::Positive, ElementType::Polygons, 3, 2, None));
+    helpers::verify_valid_output(&tess);
+    tess
+}
+
+/// Two unit squares whose shared side is off by `gap` at both ends.
+fn nearly_adjacent(gap: f64) -> Vec<Vec<f64>> {
+    vec![
+        vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0],
+        vec![1.0 + gap, 0.0, 2.0, 0.0, 2.0, 1.0, 1.0, 1.0 + gap],
+    ]
+}
+
+#[test]
+fn near_coincident_vertices_merge() {
+    let plain = welded(&nearly_adjacent(1e-10), 0.0);
+    assert_eq!(plain.weld_count(), 0);
+    assert!(plain.vertex_count() > 6);
+
+    let tess = welded(&nearly_adjacent(1e-10), 1e-6);
+    assert_eq!(tess.weld_count(), 2);
+    assert_eq!(tess.vertex_count(), 6);
+    assert_eq!(tess.element_count(), 4);
+    assert!((helpers::total_tessellation_area(&tess) - 2.0).abs() < 1e-9);
+}
+
+#[test]
+fn vertices_near_an_edge_land_on_it() {
+    // The small square's left corners sit just inside and just outside the
+    // big square's right side.
+    let contours = vec![
+        vec![0.0, 0.0, 2.0, 0.0, 2.0, 2.0, 0.0, 2.0],
+        vec![2.0 - 1e-10, 0.5, 3.0, 0.5, 3.0, 1.5, 2.0 + 1e-10, 1.5],
+    ];
+    // Unwelded, the small square's sides cross the big one's a hair away
+    // from its corners, adding intersection vertices.
+    let plain = welded(&contours, 0.0);
+    assert!(plain.vertex_count() > 8);
+
+    let tess = welded(&contours, 1e-6);
+    assert_eq!(tess.weld_count(), 2);
+    assert_eq!(tess.vertex_count(), 8);
+    assert!((helpers::total_tessellation_area(&tess) - 5.0).abs() < 1e-9);
+}
+
+#[test]
+fn clusters_do_not_chain() {
+    // Bottom side points 0.6 apart: with epsilon 1 every other one survives
+    // instead of the whole run collapsing into its first point.
+    let mut contour: Vec<f64> = (0..10).flat_map(|i| [i as f64 * 0.6, 0.0]).collect();
+    contour.extend_from_slice(&[6.0, 0.0, 6.0, 6.0, 0.0, 6.0]);
+    let tess = welded(&[contour], 1.0);
+    assert_eq!(tess.weld_count(), 5);
+    assert_eq!(tess.vertex_count(), 5 + 3);
+    assert!((helpers::total_tessellation_area(&tess) - 36.0).abs() < 1e-9);
+}
+
+#[test]
+fn welded_vertices_take_the_representative_coordinates() {
+    let mut tess = Tessellator::new();
+    tess.set_weld_epsilon(1e-6);
+    tess.add_contour(3, &[0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0]);
+    tess.add_contour(3, &[1.0 + 1e-9, 0.0, 0.0, 2.0, 0.0, 0.0, 1.0, 1.0 + 1e-9, 0.0]);
+    assert!(tess.tessellate(WindingRule::Positive, ElementType::Polygons, 3, 3, None));
+    assert_eq!(tess.weld_count(), 2);
+    assert_eq!(tess.vertex_count(), 4);
+    for v in tess.vertices().chunks(3) {
+        assert!(v[0] == v[0].round() && v[1] == v[1].round(), "vertex {:?} was not snapped", v);
+    }
+}
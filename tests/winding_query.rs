@@ -0,0 +1,100 @@
+// Copyright 2025 Lars Brubaker
+// Tests for the winding-number / point-in-polygon query.
+
+mod helpers;
+
+use tess2_rust::{ElementType, Tessellator, WindingRule};
+
+fn with_contours(contours: &[Vec<f64>]) -> Tessellator {
+    let mut tess = Tessellator::new();
+    for c in contours {
+        tess.add_contour(2, c);
+    }
+    tess
+}
+
+/// Is `p` strictly inside one of the output triangles?
+fn covered(tess: &Tessellator, p: [f64; 2]) -> bool {
+    let v = tess.vertices();
+    tess.elements().chunks(3).any(|tri| {
+        let q = |k: usize| [v[tri[k] as usize * 2], v[tri[k] as usize * 2 + 1]];
+        let (a, b, c) = (q(0), q(1), q(2));
+        let d = |u: [f64; 2], w: [f64; 2]| (w[0] - u[0]) * (p[1] - u[1]) - (w[1] - u[1]) * (p[0] - u[0]);
+        let (x, y, z) = (d(a, b), d(b, c), d(c, a));
+        (x > 0.0 && y > 0.0 && z > 0.0) || (x < 0.0 && y < 0.0 && z < 0.0)
+    })
+}
+
+fn star(n: usize, step: usize, radius: f64, clockwise: bool) -> Vec<f64> {
+    let mut c: Vec<f64> = (0..n)
+        .flat_map(|i| {
+            let a = 0.1 + (i * step) as f64 * std::f64::consts::TAU / n as f64;
+            [radius * a.cos(), radius * a.sin()]
+        })
+        .collect();
+    if clockwise {
+        c = c.chunks(2).rev().flatten().copied().collect();
+    }
+    c
+}
+
+#[test]
+fn matches_tessellation_on_a_grid() {
+    let contours = vec![star(7, 3, 10.0, false), star(5, 2, 6.0, true), star(4, 1, 3.0, false)];
+    let probe = with_contours(&contours);
+    let points: Vec<f64> = (0..41)
+        .flat_map(|i| (0..41).flat_map(move |j| [i as f64 * 0.5 - 10.03, j as f64 * 0.5 - 9.97]))
+        .collect();
+    let windings = probe.winding_numbers(2, &points, None);
+    assert_eq!(windings.len(), points.len() / 2);
+    let rules = [
+        WindingRule::Odd,
+        WindingRule::NonZero,
+        WindingRule::Positive,
+        WindingRule::Negative,
+        WindingRule::AbsGeqTwo,
+    ];
+    for rule in rules {
+        let tess = helpers::tessellate_contours(&contours, rule);
+        for (p, &w) in points.chunks(2).zip(&windings) {
+            assert_eq!(rule.is_inside(w), covered(&tess, [p[0], p[1]]), "{:?} at {:?}: winding {}", rule, p, w);
+        }
+    }
+}
+
+#[test]
+fn points_on_edges_and_vertices_are_half_open() {
+    let tess = with_contours(&[vec![0.0, 0.0, 2.0, 0.0, 2.0, 2.0, 0.0, 2.0]]);
+    let at = |x: f64, y: f64| tess.winding_number([x, y, 0.0], None);
+    assert_eq!(at(1.0, 1.0), 1);
+    // Left and bottom edges are in, right and top edges are out.
+    assert_eq!((at(0.0, 1.0), at(1.0, 0.0)), (1, 1));
+    assert_eq!((at(2.0, 1.0), at(1.0, 2.0)), (0, 0));
+    // Corners: only the bottom-left one is in.
+    assert_eq!((at(0.0, 0.0), at(2.0, 0.0), at(2.0, 2.0), at(0.0, 2.0)), (1, 0, 0, 0));
+}
+
+#[test]
+fn clockwise_input_counts_like_the_sweep() {
+    // With a computed normal the sweep mirrors t for clockwise input, so a
+    // lone clockwise square has winding +1 and fills under Positive.
+    let square_cw = vec![0.0, 0.0, 0.0, 2.0, 2.0, 2.0, 2.0, 0.0];
+    let tess = with_contours(std::slice::from_ref(&square_cw));
+    assert_eq!(tess.winding_number([1.0, 1.0, 0.0], None), 1);
+    assert_eq!(tess.winding_number([1.0, 1.0, 0.0], Some([0.0, 0.0, 1.0])), -1);
+    let filled = helpers::tessellate_contours(&[square_cw], WindingRule::Positive);
+    assert_eq!(filled.element_count(), 2);
+}
+
+#[test]
+fn three_d_contours_and_empty_input() {
+    let mut tess = Tessellator::new();
+    assert_eq!(tess.winding_numbers(2, &[0.0, 0.0, 1.0, 1.0], None), vec![0, 0]);
+    // A square in the x-z plane, queried by x and z.
+    tess.add_contour(3, &[0.0, 5.0, 0.0, 4.0, 5.0, 0.0, 4.0, 5.0, 4.0, 0.0, 5.0, 4.0]);
+    let w = tess.winding_numbers(3, &[2.0, 5.0, 2.0, 6.0, 5.0, 2.0], None);
+    assert_eq!(w, vec![1, 0]);
+    // The query leaves the contours in place for tessellation.
+    assert!(tess.tessellate(WindingRule::NonZero, ElementType::Polygons, 3, 3, None));
+    assert_eq!(tess.element_count(), 2);
+}